@@ -0,0 +1,206 @@
+use std::io::BufRead;
+
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+pub struct PipeMaze;
+
+#[derive(PartialEq, Debug)]
+pub enum Pipe {
+    Start,
+    Vertical,
+    Horizontal,
+    NorthToEast,
+    NorthToWest,
+    SouthToWest,
+    SouthToEast,
+    Ground,
+}
+
+impl Pipe {
+    fn directions(&self) -> (Direction, Direction) {
+        match self {
+            Pipe::Vertical => (Direction::North, Direction::South),
+            Pipe::Horizontal => (Direction::East, Direction::West),
+            Pipe::NorthToEast => (Direction::North, Direction::East),
+            Pipe::NorthToWest => (Direction::North, Direction::West),
+            Pipe::SouthToEast => (Direction::South, Direction::East),
+            Pipe::SouthToWest => (Direction::South, Direction::West),
+            _ => panic!("cannot determine directions for {:?}", self),
+        }
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '|' => Self::Vertical,
+            '-' => Self::Horizontal,
+            'L' => Self::NorthToEast,
+            'J' => Self::NorthToWest,
+            '7' => Self::SouthToWest,
+            'F' => Self::SouthToEast,
+            '.' => Self::Ground,
+            'S' => Self::Start,
+            _ => return Err(ParseError::at_char(1, value, "pipe")),
+        })
+    }
+}
+
+struct PipeLoop {
+    checked: Grid<bool>,
+    length: usize,
+    start: Position,
+    start_pipe: Pipe,
+}
+
+// walks the pipes from the start, assuming the start tile hides `start_pipe`.
+// Returns `None` if the walk does not lead back into the start through the
+// other end of `start_pipe`
+fn walk_loop(map: &Grid<Pipe>, start: Position, start_pipe: Pipe) -> Option<PipeLoop> {
+    let mut checked = Grid::new(map.width(), map.height(), false);
+    let (mut dir, end_dir) = start_pipe.directions();
+    let mut curr = start;
+    let mut length = 0;
+
+    loop {
+        checked[curr] = true;
+        curr = map.step(curr, dir)?;
+        length += 1;
+
+        let curr_pipe = &map[curr];
+        match curr_pipe {
+            Pipe::Start if dir == end_dir.opposite() => break,
+            Pipe::Start | Pipe::Ground => return None,
+            _ => {}
+        }
+
+        // leave the pipe through the end we did not come in from
+        dir = match curr_pipe.directions() {
+            (dir1, dir2) if dir1 == dir.opposite() => dir2,
+            (dir1, dir2) if dir2 == dir.opposite() => dir1,
+            _ => return None,
+        };
+    }
+
+    Some(PipeLoop {
+        checked,
+        length,
+        start,
+        start_pipe,
+    })
+}
+
+fn find_loop(map: &Grid<Pipe>) -> Option<PipeLoop> {
+    let start = map.position(|pipe| pipe == &Pipe::Start)?;
+
+    // the start tile hides one of the pipes, which is the one closing the loop
+    [
+        Pipe::Vertical,
+        Pipe::Horizontal,
+        Pipe::NorthToEast,
+        Pipe::NorthToWest,
+        Pipe::SouthToEast,
+        Pipe::SouthToWest,
+    ]
+    .into_iter()
+    .find_map(|start_pipe| walk_loop(map, start, start_pipe))
+}
+
+impl Solution for PipeMaze {
+    type Input = Grid<Pipe>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        let map = Grid::parse(reader, Pipe::try_from)?;
+
+        let Some((start_y, start_x)) = map.position(|pipe| pipe == &Pipe::Start) else {
+            return Err(ParseError::end_of("", "start tile `S`")
+                .at_line(map.height() + 1)
+                .into());
+        };
+
+        if find_loop(&map).is_none() {
+            return Err(
+                ParseError::at_char(start_x + 1, 'S', "start tile on a loop")
+                    .at_line(start_y + 1)
+                    .into(),
+            );
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        find_loop(map).expect("parsing checks for a loop").length / 2
+    }
+
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let PipeLoop {
+            checked,
+            start,
+            start_pipe,
+            ..
+        } = find_loop(map).expect("parsing checks for a loop");
+
+        let mut sum = 0;
+        for y in 0..checked.height() {
+            let mut in_loop = false;
+            for x in 0..checked.width() {
+                let pipe = if (y, x) == start {
+                    &start_pipe
+                } else {
+                    &map[(y, x)]
+                };
+
+                if checked[(y, x)] {
+                    if [Pipe::Vertical, Pipe::SouthToWest, Pipe::SouthToEast].contains(pipe) {
+                        in_loop = !in_loop;
+                    }
+
+                    continue;
+                }
+
+                if in_loop {
+                    sum += 1;
+                }
+            }
+        }
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_a_start_tile() {
+        let err = PipeMaze::parse_str("F7\nLJ").err().unwrap();
+
+        assert_eq!(err.line(), Some(3));
+        assert_eq!(err.expected(), "start tile `S`");
+    }
+
+    #[test]
+    fn requires_the_start_on_a_loop() {
+        let err = PipeMaze::parse_str(".....\n.F-7.\n.|.|.\n.L-J.\n..S..")
+            .err()
+            .unwrap();
+
+        assert_eq!((err.line(), err.column()), (Some(5), 3));
+        assert_eq!(err.expected(), "start tile on a loop");
+    }
+
+    #[test]
+    fn finds_the_loop_through_the_start() {
+        let map = PipeMaze::parse_str(".....\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+
+        assert_eq!(PipeMaze::part1(&map), 4);
+        assert_eq!(PipeMaze::part2(&map), 1);
+    }
+}
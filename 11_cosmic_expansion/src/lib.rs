@@ -0,0 +1,94 @@
+use std::io::BufRead;
+
+use common::{Error, ParseError, Solution};
+use grid::Grid;
+
+pub struct CosmicExpansion;
+
+#[derive(PartialEq, Clone)]
+pub enum Item {
+    Empty(usize),
+    Galaxy,
+}
+
+fn sum_galaxy_distances(space: &Grid<Item>, expansion: usize) -> usize {
+    let mut expanded_space = space.clone();
+
+    for (row_idx, row) in space.rows().enumerate() {
+        if row.contains(&Item::Galaxy) {
+            continue;
+        }
+
+        expanded_space.row_mut(row_idx).fill(Item::Empty(expansion));
+    }
+
+    for (col_idx, mut column) in space.columns().enumerate() {
+        if column.any(|item| item == &Item::Galaxy) {
+            continue;
+        }
+
+        for row in expanded_space.rows_mut() {
+            row[col_idx] = Item::Empty(expansion);
+        }
+    }
+
+    let galaxy_positions: Vec<(usize, usize)> = expanded_space
+        .iter()
+        .filter(|(_, i)| *i == &Item::Galaxy)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut galaxy_combinations: Vec<((usize, usize), (usize, usize))> = vec![];
+    for i in 0..galaxy_positions.len() {
+        for j in i..galaxy_positions.len() {
+            galaxy_combinations.push((galaxy_positions[i], galaxy_positions[j]));
+        }
+    }
+
+    galaxy_combinations
+        .iter()
+        .map(|(i, j)| {
+            let mut distance = 0;
+
+            let y_range = if i.0 > j.0 { j.0..i.0 } else { i.0..j.0 };
+            for y in y_range {
+                distance += match expanded_space[(y, i.1)] {
+                    Item::Galaxy => 1,
+                    Item::Empty(x) => x,
+                };
+            }
+
+            let x_range = if i.1 > j.1 { j.1..i.1 } else { i.1..j.1 };
+            for x in x_range {
+                distance += match expanded_space[(i.0, x)] {
+                    Item::Galaxy => 1,
+                    Item::Empty(x) => x,
+                };
+            }
+
+            distance
+        })
+        .sum()
+}
+
+impl Solution for CosmicExpansion {
+    type Input = Grid<Item>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        Grid::parse(reader, |c| match c {
+            '#' => Ok(Item::Galaxy),
+            '.' => Ok(Item::Empty(1)),
+            _ => Err(ParseError::at_char(1, c, "`#` or `.`")),
+        })
+    }
+
+    fn part1(space: &Self::Input) -> Self::Part1 {
+        sum_galaxy_distances(space, 2)
+    }
+
+    fn part2(space: &Self::Input) -> Self::Part2 {
+        sum_galaxy_distances(space, 1000000)
+    }
+}
@@ -1,28 +1,41 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader, Error},
-};
+use std::{collections::HashMap, io::BufRead};
+
+use common::{parse_token, split_once, Error, ParseError, Solution};
+
+pub struct HotSprings;
 
 #[derive(Debug)]
-struct SpringRow {
+pub struct SpringRow {
     map: String,
     springs: Vec<usize>,
 }
 
-fn parse_input(line: String) -> SpringRow {
-    let (map, springs) = line
-        .split_once(' ')
-        .expect("map and springs are seperated by a space");
+fn parse_input(line: &str) -> Result<SpringRow, ParseError> {
+    // map and springs are seperated by a space
+    let (map, springs) = split_once(line, " ")?;
+
+    if let Some((idx, c)) = map
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+    {
+        return Err(ParseError::at_char(idx + 1, c, "`.`, `#` or `?`"));
+    }
 
     let springs = springs
         .split(',')
-        .map(|group| group.parse::<usize>().expect("group is number"))
-        .collect::<Vec<usize>>();
+        .map(|group| parse_token::<usize>(line, group, "group size"))
+        .collect::<Result<Vec<usize>, _>>()?;
 
-    let mut map = std::iter::repeat(())
-        .take(5)
-        .map(|_| format!("{map}?"))
+    Ok(SpringRow {
+        map: map.to_string(),
+        springs,
+    })
+}
+
+fn unfold(spring_row: &SpringRow, times: usize) -> SpringRow {
+    let mut map = std::iter::repeat_n((), times)
+        .map(|_| format!("{}?", spring_row.map))
         .collect::<String>();
 
     // split off last ?
@@ -30,10 +43,25 @@ fn parse_input(line: String) -> SpringRow {
 
     SpringRow {
         map,
-        springs: springs.repeat(5),
+        springs: spring_row.springs.repeat(times),
     }
 }
 
+fn sum_arrangements(spring_rows: &[SpringRow], unfold_times: usize) -> usize {
+    spring_rows
+        .iter()
+        .map(|spring_row| unfold(spring_row, unfold_times))
+        .map(|spring_row| {
+            let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
+            calculate_max_spring_combinations(
+                spring_row.map.as_bytes(),
+                &spring_row.springs,
+                &mut cache,
+            )
+        })
+        .sum()
+}
+
 fn calculate_max_spring_combinations(
     map: &[u8],
     springs: &[usize],
@@ -44,7 +72,7 @@ fn calculate_max_spring_combinations(
     }
 
     if springs.is_empty() {
-        if map.iter().any(|&entry| entry == b'#') {
+        if map.contains(&b'#') {
             return 0;
         }
 
@@ -66,7 +94,7 @@ fn calculate_max_spring_combinations(
             break;
         }
 
-        if map[i..i + group_len].iter().any(|&entry| entry == b'.') {
+        if map[i..i + group_len].contains(&b'.') {
             // group does not fit into first part of current map
             continue;
         }
@@ -90,24 +118,26 @@ fn calculate_max_spring_combinations(
     sum
 }
 
-fn main() -> Result<(), Error> {
-    let input = File::open("input.txt")?;
-    let reader = BufReader::new(input);
-
-    let sum: usize = reader
-        .lines()
-        .map(|line| parse_input(line.expect("valid line")))
-        .map(|spring_row| {
-            let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
-            calculate_max_spring_combinations(
-                spring_row.map.as_bytes(),
-                &spring_row.springs,
-                &mut cache,
-            )
-        })
-        .sum();
+impl Solution for HotSprings {
+    type Input = Vec<SpringRow>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                Ok(parse_input(&line?).map_err(|err| err.at_line(line_idx + 1))?)
+            })
+            .collect()
+    }
 
-    println!("Sum: {sum}");
+    fn part1(spring_rows: &Self::Input) -> Self::Part1 {
+        sum_arrangements(spring_rows, 1)
+    }
 
-    Ok(())
+    fn part2(spring_rows: &Self::Input) -> Self::Part2 {
+        sum_arrangements(spring_rows, 5)
+    }
 }
@@ -0,0 +1,178 @@
+use std::io::BufRead;
+
+use common::{Error, ParseError, Part, Solution};
+use grid::Grid;
+
+pub struct Mirrors;
+
+pub struct Pattern {
+    map: Grid<char>,
+    // the line the pattern starts on, for errors found after parsing
+    line: usize,
+}
+
+fn parse_cell(c: char) -> Result<char, ParseError> {
+    match c {
+        '#' | '.' => Ok(c),
+        _ => Err(ParseError::at_char(1, c, "`#` or `.`")),
+    }
+}
+
+fn parse_input(reader: impl BufRead) -> Result<Vec<Pattern>, Error> {
+    let mut lines = reader.lines().enumerate();
+    let mut results: Vec<Pattern> = vec![];
+
+    while let Some((line_idx, line)) = lines.next() {
+        let mut curr_input = Grid::default();
+        curr_input
+            .parse_row(&line?, parse_cell)
+            .map_err(|err| err.at_line(line_idx + 1))?;
+
+        for (line_idx, line) in lines.by_ref() {
+            let line = line?;
+            if line.is_empty() {
+                break;
+            }
+
+            curr_input
+                .parse_row(&line, parse_cell)
+                .map_err(|err| err.at_line(line_idx + 1))?;
+        }
+
+        results.push(Pattern {
+            map: curr_input,
+            line: line_idx + 1,
+        });
+    }
+
+    Ok(results)
+}
+
+enum Mirror {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+fn get_mirror(map: &Grid<char>, smudges: usize) -> Option<Mirror> {
+    if let Some(vert) = try_vertical_mirror(map, smudges) {
+        return Some(Mirror::Vertical(vert));
+    }
+
+    try_horizontal_mirror(map, smudges).map(Mirror::Horizontal)
+}
+
+fn try_vertical_mirror(map: &Grid<char>, smudges: usize) -> Option<usize> {
+    try_horizontal_mirror(&map.transpose(), smudges)
+}
+
+fn try_horizontal_mirror(map: &Grid<char>, smudges: usize) -> Option<usize> {
+    for mirror_index in 1..map.height() {
+        let mirror_range = std::cmp::min(mirror_index, map.height() - mirror_index);
+
+        let mut wrong_mirror_count = 0;
+
+        for compare_index in 1..=mirror_range {
+            let line1 = map.row(mirror_index - compare_index);
+            let line2 = map.row(mirror_index + compare_index - 1);
+
+            wrong_mirror_count += line1
+                .iter()
+                .zip(line2.iter())
+                .filter(|(x, y)| x != y)
+                .count();
+        }
+
+        if wrong_mirror_count == smudges {
+            return Some(mirror_index);
+        }
+    }
+
+    None
+}
+
+// fails for the first pattern without a mirror after fixing `smudges` cells
+fn check_mirrors(patterns: &[Pattern], smudges: usize) -> Result<(), ParseError> {
+    let Some(pattern) = patterns
+        .iter()
+        .find(|pattern| get_mirror(&pattern.map, smudges).is_none())
+    else {
+        return Ok(());
+    };
+
+    let expected = match smudges {
+        0 => "pattern with a line of reflection".to_string(),
+        _ => format!("pattern with a line of reflection after fixing {smudges} smudge"),
+    };
+    let first_row: String = pattern.map.row(0).iter().collect();
+
+    Err(ParseError::new(1, expected, Some(&first_row)).at_line(pattern.line))
+}
+
+fn summarize_notes(patterns: &[Pattern], smudges: usize) -> usize {
+    patterns
+        .iter()
+        .map(|pattern| get_mirror(&pattern.map, smudges).expect("checked for a mirror"))
+        .map(|result| match result {
+            Mirror::Vertical(index) => index,
+            Mirror::Horizontal(index) => index * 100,
+        })
+        .sum()
+}
+
+impl Solution for Mirrors {
+    type Input = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        parse_input(reader)
+    }
+
+    fn check(patterns: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::One => check_mirrors(patterns, 0),
+            Part::Two => check_mirrors(patterns, 1),
+        }
+    }
+
+    fn part1(patterns: &Self::Input) -> Self::Part1 {
+        summarize_notes(patterns, 0)
+    }
+
+    fn part2(patterns: &Self::Input) -> Self::Part2 {
+        summarize_notes(patterns, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // mirrored between the first two rows, and nowhere after fixing a smudge
+    const PERFECT: &str = "#.#\n#.#";
+
+    #[test]
+    fn reports_patterns_without_a_mirror() {
+        let patterns = Mirrors::parse_str(&format!("{PERFECT}\n\n#..\n.#.\n..#")).unwrap();
+
+        let err = Mirrors::check(&patterns, Part::One).unwrap_err();
+        assert_eq!(err.line(), Some(4));
+        assert_eq!(err.expected(), "pattern with a line of reflection");
+        assert_eq!(err.found(), Some("#.."));
+    }
+
+    #[test]
+    fn checks_each_part_on_its_own() {
+        let patterns = Mirrors::parse_str(PERFECT).unwrap();
+
+        assert_eq!(Mirrors::check(&patterns, Part::One), Ok(()));
+        assert_eq!(Mirrors::part1(&patterns), 100);
+
+        let err = Mirrors::check(&patterns, Part::Two).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(
+            err.expected(),
+            "pattern with a line of reflection after fixing 1 smudge"
+        );
+    }
+}
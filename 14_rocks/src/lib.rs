@@ -0,0 +1,104 @@
+use std::{collections::HashMap, io::BufRead};
+
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+pub struct Rocks;
+
+fn parse_input(reader: impl BufRead) -> Result<Grid<char>, Error> {
+    Grid::parse(reader, |c| match c {
+        'O' | '#' | '.' => Ok(c),
+        _ => Err(ParseError::at_char(1, c, "`O`, `#` or `.`")),
+    })
+}
+
+fn move_rock(pos: Position, direction: Direction, map: &mut Grid<char>) {
+    if map[pos] != 'O' {
+        return;
+    }
+
+    let mut new_pos = pos;
+    while let Some(next) = map
+        .step(new_pos, direction)
+        .filter(|next| map[*next] == '.')
+    {
+        new_pos = next;
+    }
+
+    map[pos] = '.';
+    map[new_pos] = 'O';
+}
+
+fn tilt_map(map: &mut Grid<char>, direction: Direction) {
+    let mut positions: Vec<Position> = map.positions().collect();
+
+    // rocks closest to the edge we tilt towards have to move first
+    if matches!(direction, Direction::South | Direction::East) {
+        positions.reverse();
+    }
+
+    for pos in positions {
+        move_rock(pos, direction, map);
+    }
+}
+
+fn calculate_load(map: &Grid<char>) -> usize {
+    map.rows()
+        .rev()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            let rock_amount = row.iter().filter(|&item| item == &'O').count();
+
+            (row_idx + 1) * rock_amount
+        })
+        .sum()
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+impl Solution for Rocks {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        parse_input(reader)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        let mut map = map.clone();
+        tilt_map(&mut map, Direction::North);
+
+        calculate_load(&map)
+    }
+
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let mut map = map.clone();
+        let mut cache: HashMap<Grid<char>, usize> = HashMap::new();
+        let mut iterator = 0..1_000_000_000;
+        let mut hit_cache = false;
+
+        while let Some(counter) = iterator.next() {
+            DIRECTIONS.iter().for_each(|dir| {
+                tilt_map(&mut map, *dir);
+            });
+
+            if let Some(cached) = cache.get(&map) {
+                if !hit_cache {
+                    let skippable = counter - cached;
+                    iterator.nth((((1_000_000_000 - counter) / skippable) * skippable) - 1);
+                    hit_cache = true;
+                }
+            }
+
+            cache.insert(map.clone(), counter);
+        }
+
+        calculate_load(&map)
+    }
+}
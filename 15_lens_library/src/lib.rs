@@ -0,0 +1,130 @@
+use std::io::BufRead;
+
+use common::{column_of, Error, ParseError, Solution};
+
+pub struct LensLibrary;
+
+fn hash_string(input: &str) -> usize {
+    input.chars().fold(0, |mut acc, c| {
+        acc += c as usize;
+        acc *= 17;
+
+        acc % 256
+    })
+}
+
+// a step is a label followed by either `-` or `=` and a single digit focal length
+fn validate_step(line: &str, step: &str) -> Result<(), ParseError> {
+    let label_len = step
+        .find(|c: char| !c.is_alphanumeric())
+        .ok_or_else(|| ParseError::end_of(step, "`-` or `=`").shifted(column_of(line, step) - 1))?;
+
+    let operation = &step[label_len..];
+    let column = column_of(line, operation);
+
+    match operation.as_bytes() {
+        [b'-'] => Ok(()),
+        [b'=', focal_length] if focal_length.is_ascii_digit() => Ok(()),
+        [b'=', ..] => Err(ParseError::new(
+            column + 1,
+            "single digit focal length",
+            Some(&operation[1..]),
+        )),
+        [b'-', ..] => Err(ParseError::new(column + 1, "`,`", Some(&operation[1..]))),
+        _ => {
+            let found = operation
+                .chars()
+                .next()
+                .expect("label stops before a symbol");
+            Err(ParseError::at_char(column, found, "`-` or `=`"))
+        }
+    }
+}
+
+fn focusing_power(instructions: &[String]) -> usize {
+    let mut boxes: Vec<Vec<(String, usize)>> = vec![vec![]; 256];
+
+    for instruction in instructions {
+        let instruction_type_idx = instruction
+            .chars()
+            .position(|c| !c.is_alphanumeric())
+            .expect("instruction contains symbol");
+
+        let label = &instruction[0..instruction_type_idx];
+        let box_idx = hash_string(label);
+        let instruction_type = instruction.as_bytes()[instruction_type_idx];
+
+        match instruction_type {
+            b'-' => {
+                if let Some(lens_idx) = boxes[box_idx]
+                    .iter()
+                    .position(|(item_label, _)| item_label == label)
+                {
+                    boxes[box_idx].remove(lens_idx);
+                };
+            }
+            b'=' => {
+                let focal_length = instruction
+                    .chars()
+                    .nth(instruction_type_idx + 1)
+                    .expect("char after instruction type")
+                    .to_digit(10)
+                    .expect("last char is digit");
+
+                match boxes[box_idx]
+                    .iter()
+                    .position(|(item_label, _)| item_label == label)
+                {
+                    Some(existing_idx) => {
+                        boxes[box_idx][existing_idx].1 = focal_length as usize;
+                    }
+                    None => boxes[box_idx].push((label.to_string(), focal_length as usize)),
+                }
+            }
+            _ => panic!("Unexpected instruction type {instruction_type}"),
+        };
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_idx, bx)| {
+            bx.iter()
+                .enumerate()
+                .map(|(lens_idx, (_, focal_length))| (1 + box_idx) * (1 + lens_idx) * *focal_length)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+impl Solution for LensLibrary {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        let mut instructions = vec![];
+
+        for (line_idx, line) in reader.lines().enumerate() {
+            let line = line?;
+
+            for step in line.split(',') {
+                validate_step(&line, step).map_err(|err| err.at_line(line_idx + 1))?;
+                instructions.push(step.to_string());
+            }
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Input) -> Self::Part1 {
+        instructions
+            .iter()
+            .map(|instruction| hash_string(instruction))
+            .sum()
+    }
+
+    fn part2(instructions: &Self::Input) -> Self::Part2 {
+        focusing_power(instructions)
+    }
+}
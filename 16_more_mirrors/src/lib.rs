@@ -0,0 +1,114 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+pub struct MoreMirrors;
+
+// moves the beam one tile in `dir`, dropping it once it leaves the map
+fn push_beam(
+    queue: &mut VecDeque<(Position, Direction)>,
+    map: &Grid<char>,
+    pos: Position,
+    dir: Direction,
+) {
+    if let Some(next) = map.step(pos, dir) {
+        queue.push_back((next, dir));
+    }
+}
+
+fn calculate_energized_tiles(map: &Grid<char>, start: Position, start_dir: Direction) -> usize {
+    let mut checked: Grid<Vec<Direction>> = Grid::new(map.width(), map.height(), vec![]);
+    let mut queue: VecDeque<(Position, Direction)> = VecDeque::new();
+    queue.push_back((start, start_dir));
+
+    while let Some((pos, dir)) = queue.pop_front() {
+        if checked[pos].contains(&dir) {
+            continue;
+        }
+
+        checked[pos].push(dir);
+
+        match map[pos] {
+            '.' => push_beam(&mut queue, map, pos, dir),
+            '/' => match dir {
+                Direction::North => push_beam(&mut queue, map, pos, Direction::East),
+                Direction::East => push_beam(&mut queue, map, pos, Direction::North),
+                Direction::South => push_beam(&mut queue, map, pos, Direction::West),
+                Direction::West => push_beam(&mut queue, map, pos, Direction::South),
+            },
+            '\\' => match dir {
+                Direction::North => push_beam(&mut queue, map, pos, Direction::West),
+                Direction::East => push_beam(&mut queue, map, pos, Direction::South),
+                Direction::South => push_beam(&mut queue, map, pos, Direction::East),
+                Direction::West => push_beam(&mut queue, map, pos, Direction::North),
+            },
+            '-' => match dir {
+                Direction::North | Direction::South => {
+                    push_beam(&mut queue, map, pos, Direction::East);
+                    push_beam(&mut queue, map, pos, Direction::West);
+                }
+                _ => push_beam(&mut queue, map, pos, dir),
+            },
+            '|' => match dir {
+                Direction::East | Direction::West => {
+                    push_beam(&mut queue, map, pos, Direction::North);
+                    push_beam(&mut queue, map, pos, Direction::South);
+                }
+                _ => push_beam(&mut queue, map, pos, dir),
+            },
+            c => unreachable!("unexpected char {c}"),
+        }
+    }
+
+    checked.iter().filter(|(_, cell)| !cell.is_empty()).count()
+}
+
+impl Solution for MoreMirrors {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        Grid::parse(reader, |c| match c {
+            '.' | '/' | '\\' | '-' | '|' => Ok(c),
+            _ => Err(ParseError::at_char(1, c, "`.`, `/`, `\\`, `-` or `|`")),
+        })
+    }
+
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        calculate_energized_tiles(map, (0, 0), Direction::East)
+    }
+
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let mut max_part_2 = 0;
+
+        for i in 0..map.height() {
+            let energized_tiles = calculate_energized_tiles(map, (i, 0), Direction::East);
+            if energized_tiles > max_part_2 {
+                max_part_2 = energized_tiles;
+            }
+
+            let energized_tiles =
+                calculate_energized_tiles(map, (i, map.width() - 1), Direction::West);
+            if energized_tiles > max_part_2 {
+                max_part_2 = energized_tiles;
+            }
+        }
+
+        for i in 0..map.width() {
+            let energized_tiles = calculate_energized_tiles(map, (0, i), Direction::South);
+            if energized_tiles > max_part_2 {
+                max_part_2 = energized_tiles;
+            }
+
+            let energized_tiles =
+                calculate_energized_tiles(map, (map.height() - 1, i), Direction::North);
+            if energized_tiles > max_part_2 {
+                max_part_2 = energized_tiles;
+            }
+        }
+
+        max_part_2
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    io::BufRead,
+};
+
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+pub struct ClumsyCrucible;
+
+#[derive(PartialEq, Eq, Debug)]
+struct State {
+    pos: Position,
+    cost: u32,
+    direction: Direction,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn find_min_cost_path(
+    map: &Grid<u32>,
+    start_direction: Direction,
+    min_distance: usize,
+    max_distance: usize,
+) -> usize {
+    let mut queue: BinaryHeap<State> = BinaryHeap::new();
+    let mut checked: Grid<Vec<Direction>> = Grid::new(map.width(), map.height(), vec![]);
+    let mut costs: HashMap<(Position, Direction), u32> = HashMap::new();
+    let target = (map.height() - 1, map.width() - 1);
+
+    queue.push(State {
+        pos: (0, 0),
+        cost: 0,
+        direction: start_direction,
+    });
+
+    while let Some(state) = queue.pop() {
+        if state.pos == target {
+            return state.cost as usize;
+        }
+
+        if checked[state.pos].contains(&state.direction) {
+            continue;
+        }
+
+        checked[state.pos].push(state.direction);
+
+        // the crucible can neither go straight on nor reverse after a run
+        for direction in [state.direction.turn_left(), state.direction.turn_right()] {
+            let mut cost_increase = 0;
+            for distance in 1..=max_distance {
+                let Some(new_pos) = map.step_by(state.pos, direction, distance) else {
+                    break;
+                };
+
+                cost_increase += map[new_pos];
+
+                if distance < min_distance {
+                    continue;
+                }
+
+                let new_cost = state.cost + cost_increase;
+                let existing_cost = costs.get(&(new_pos, direction)).unwrap_or(&u32::MAX);
+
+                if *existing_cost <= new_cost {
+                    continue;
+                }
+
+                costs.insert((new_pos, direction), new_cost);
+                queue.push(State {
+                    pos: new_pos,
+                    direction,
+                    cost: new_cost,
+                })
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+impl Solution for ClumsyCrucible {
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        Grid::parse(reader, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::at_char(1, c, "heat loss digit"))
+        })
+    }
+
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        std::cmp::min(
+            find_min_cost_path(map, Direction::East, 1, 3),
+            find_min_cost_path(map, Direction::South, 1, 3),
+        )
+    }
+
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        std::cmp::min(
+            find_min_cost_path(map, Direction::East, 4, 10),
+            find_min_cost_path(map, Direction::South, 4, 10),
+        )
+    }
+}
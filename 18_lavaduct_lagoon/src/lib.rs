@@ -0,0 +1,135 @@
+use std::{io::BufRead, str::FromStr};
+
+use common::{column_of, parse_token, Error, ParseError, Solution};
+use grid::{Direction, Point};
+
+pub struct LavaductLagoon;
+
+fn parse_direction(s: &str) -> Result<Direction, ParseError> {
+    Ok(match s {
+        "R" => Direction::East,
+        "D" => Direction::South,
+        "L" => Direction::West,
+        "U" => Direction::North,
+        _ => return Err(ParseError::new(1, "direction (R, D, L or U)", Some(s))),
+    })
+}
+
+fn direction_from_digit(c: char) -> Result<Direction, ParseError> {
+    Ok(match c {
+        '0' => Direction::East,
+        '1' => Direction::South,
+        '2' => Direction::West,
+        '3' => Direction::North,
+        _ => return Err(ParseError::at_char(1, c, "direction digit (0-3)")),
+    })
+}
+
+#[derive(Debug)]
+pub struct Command {
+    direction: Direction,
+    length: isize,
+    length_2: isize,
+    direction_2: Direction,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+        let mut next_token =
+            |expected: &str| split.next().ok_or_else(|| ParseError::end_of(s, expected));
+
+        let direction_token = next_token("direction")?;
+        let direction = parse_direction(direction_token)
+            .map_err(|err: ParseError| err.shifted(column_of(s, direction_token) - 1))?;
+        let length = parse_token::<isize>(s, next_token("length")?, "length")?;
+
+        // the color is `(#` followed by five hex digits of length and one direction digit
+        let color = next_token("color")?;
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| ParseError::at(s, color, "color (`(#` and six hex digits `)`)"))?;
+
+        let length_2 = isize::from_str_radix(&hex[..5], 16).expect("validated hex digits");
+        let direction_2 = direction_from_digit(hex.as_bytes()[5] as char)
+            .map_err(|err| err.shifted(column_of(s, hex) + 4))?;
+
+        if let Some(extra) = split.next() {
+            return Err(ParseError::at(s, extra, "end of line"));
+        }
+
+        Ok(Command {
+            direction,
+            length,
+            length_2,
+            direction_2,
+        })
+    }
+}
+
+fn shoelace_formula(vertices: &[Point]) -> isize {
+    let sum: isize = vertices
+        .windows(2)
+        .map(|verts| (verts[0].y + verts[1].y) * (verts[0].x - verts[1].x))
+        .sum();
+
+    sum / 2
+}
+
+fn picks_theorem(interior_cnt: isize, circumference: isize) -> isize {
+    interior_cnt + (circumference / 2) + 1
+}
+
+fn dig_area(commands: impl Iterator<Item = (Direction, isize)>) -> isize {
+    let mut curr = Point::ORIGIN;
+    let mut vertices: Vec<Point> = vec![curr];
+    let mut circumference: isize = 0;
+
+    for (direction, length) in commands {
+        curr = curr.step_by(direction, length);
+
+        circumference += length;
+        vertices.push(curr);
+    }
+
+    let shoelace_result = shoelace_formula(&vertices);
+    picks_theorem(shoelace_result, circumference)
+}
+
+impl Solution for LavaductLagoon {
+    type Input = Vec<Command>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                Ok(line?
+                    .parse()
+                    .map_err(|err: ParseError| err.at_line(line_idx + 1))?)
+            })
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Self::Part1 {
+        dig_area(
+            commands
+                .iter()
+                .map(|command| (command.direction, command.length)),
+        )
+    }
+
+    fn part2(commands: &Self::Input) -> Self::Part2 {
+        dig_area(
+            commands
+                .iter()
+                .map(|command| (command.direction_2, command.length_2)),
+        )
+    }
+}
@@ -0,0 +1,414 @@
+use std::{collections::HashMap, io::BufRead, ops::Range, str::FromStr};
+
+use common::{column_of, parse_token, split_once, Error, ParseError, Solution};
+
+pub struct Aplenty;
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    part_ratings: Vec<PartRating>,
+}
+
+#[derive(Debug)]
+enum RuleOperation {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug)]
+enum Rule {
+    AlwaysTrue(String),
+    Conditional {
+        attr: PartAttribute,
+        operation: RuleOperation,
+        value: usize,
+        next: String,
+    },
+}
+
+impl Rule {
+    fn apply(&self, part_rating: &PartRating) -> Option<&str> {
+        match self {
+            Self::AlwaysTrue(next) => Some(next),
+            Self::Conditional {
+                attr,
+                operation,
+                value,
+                next,
+            } => {
+                let actual_val = match attr {
+                    PartAttribute::X => part_rating.x,
+                    PartAttribute::M => part_rating.m,
+                    PartAttribute::A => part_rating.a,
+                    PartAttribute::S => part_rating.s,
+                };
+
+                let applies = match operation {
+                    RuleOperation::LessThan => actual_val < *value,
+                    RuleOperation::GreaterThan => actual_val > *value,
+                };
+
+                if applies {
+                    Some(next)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn apply(&self, part_rating: &PartRating) -> &str {
+        let rules = self.rules.iter();
+
+        for rule in rules {
+            if let Some(next) = rule.apply(part_rating) {
+                return next;
+            }
+        }
+
+        unreachable!("parsing checks workflows end in a fallback")
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules = s
+            .split(',')
+            .map(|r| {
+                let Some((condition, result)) = r.split_once(':') else {
+                    if r.is_empty() {
+                        return Err(ParseError::at(s, r, "workflow name"));
+                    }
+
+                    return Ok(Rule::AlwaysTrue(r.to_string()));
+                };
+
+                let mut cond_chars = condition.chars();
+                let column = column_of(s, condition);
+
+                let attr = match cond_chars.next() {
+                    Some('x') => PartAttribute::X,
+                    Some('m') => PartAttribute::M,
+                    Some('a') => PartAttribute::A,
+                    Some('s') => PartAttribute::S,
+                    Some(c) => return Err(ParseError::at_char(column, c, "`x`, `m`, `a` or `s`")),
+                    None => return Err(ParseError::at(s, condition, "`x`, `m`, `a` or `s`")),
+                };
+
+                let operation = match cond_chars.next() {
+                    Some('<') => RuleOperation::LessThan,
+                    Some('>') => RuleOperation::GreaterThan,
+                    Some(c) => return Err(ParseError::at_char(column + 1, c, "`<` or `>`")),
+                    None => return Err(ParseError::at(s, &condition[1..], "`<` or `>`")),
+                };
+
+                let value = parse_token::<usize>(s, cond_chars.as_str(), "condition value")?;
+
+                Ok(Rule::Conditional {
+                    attr,
+                    operation,
+                    value,
+                    next: result.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(Rule::Conditional { .. }) = rules.last() {
+            return Err(ParseError::end_of(s, "`,` and a fallback workflow"));
+        }
+
+        Ok(Workflow { rules })
+    }
+}
+
+#[derive(Debug)]
+enum PartAttribute {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl PartAttribute {
+    fn to_range_idx(&self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartRating {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl FromStr for PartRating {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut splits = s.split(',');
+
+        // ratings are always listed in `x,m,a,s` order
+        let mut next_rating = |attr: &str| {
+            let rating = splits
+                .next()
+                .ok_or_else(|| ParseError::end_of(s, format!("`,{attr}=`")))?;
+            let (name, value) =
+                split_once(rating, "=").map_err(|err| err.shifted(column_of(s, rating) - 1))?;
+
+            if name != attr {
+                return Err(ParseError::at(s, name, format!("`{attr}`")));
+            }
+
+            parse_token::<usize>(s, value, "rating")
+        };
+
+        let x = next_rating("x")?;
+        let m = next_rating("m")?;
+        let a = next_rating("a")?;
+        let s_rating = next_rating("s")?;
+
+        if let Some(extra) = splits.next() {
+            return Err(ParseError::at(s, extra, "`}`"));
+        }
+
+        Ok(PartRating {
+            x,
+            m,
+            a,
+            s: s_rating,
+        })
+    }
+}
+
+// strips the surrounding braces off `line`, mapping errors in the inner
+// parser back to columns of the whole line
+fn parse_braced<T: FromStr<Err = ParseError>>(line: &str, inner: &str) -> Result<T, ParseError> {
+    let column = column_of(line, inner);
+
+    let Some(body) = inner.strip_prefix('{') else {
+        return Err(ParseError::at(line, inner, "`{`"));
+    };
+    let Some(body) = body.strip_suffix('}') else {
+        return Err(ParseError::end_of(line, "`}`"));
+    };
+
+    body.parse().map_err(|err: ParseError| err.shifted(column))
+}
+
+// the workflows the rules of `body` send parts to, in order
+fn targets(body: &str) -> impl Iterator<Item = &str> {
+    body.split(',')
+        .map(|rule| rule.split_once(':').map_or(rule, |(_, next)| next))
+}
+
+fn parse_input(reader: impl BufRead) -> Result<System, Error> {
+    let mut lines = reader.lines().enumerate();
+    let mut workflows: HashMap<String, Workflow> = HashMap::new();
+    let mut workflow_lines = vec![];
+    let mut line_count = 0;
+
+    for (line_idx, line) in lines.by_ref() {
+        let line = line?;
+        line_count = line_idx + 1;
+        if line.is_empty() {
+            break;
+        }
+
+        let brace_idx = line
+            .find('{')
+            .ok_or_else(|| ParseError::end_of(&line, "`{`").at_line(line_idx + 1))?;
+
+        workflows.insert(
+            line[..brace_idx].to_string(),
+            parse_braced(&line, &line[brace_idx..]).map_err(|err| err.at_line(line_idx + 1))?,
+        );
+        workflow_lines.push((line_idx + 1, line));
+    }
+
+    // every rule has to send parts somewhere, starting from `in`
+    for (line_no, line) in &workflow_lines {
+        let body = &line[line.find('{').expect("line was parsed before") + 1..line.len() - 1];
+        if let Some(unknown) =
+            targets(body).find(|&next| next != "A" && next != "R" && !workflows.contains_key(next))
+        {
+            return Err(ParseError::at(line, unknown, "known workflow, `A` or `R`")
+                .at_line(*line_no)
+                .into());
+        }
+    }
+
+    if !workflows.contains_key("in") {
+        return Err(ParseError::end_of("", "workflow `in`")
+            .at_line(line_count.max(1))
+            .into());
+    }
+
+    let part_ratings = lines
+        .map(|(line_idx, l)| {
+            let l = l?;
+            Ok(parse_braced(&l, &l).map_err(|err| err.at_line(line_idx + 1))?)
+        })
+        .collect::<Result<Vec<PartRating>, Error>>()?;
+
+    Ok(System {
+        workflows,
+        part_ratings,
+    })
+}
+
+fn get_workflows_ranges(
+    workflows: &HashMap<String, Workflow>,
+    ranges: &mut [Range<usize>; 4],
+    workflow: &str,
+) -> usize {
+    if workflow == "R" {
+        return 0;
+    };
+
+    if workflow == "A" {
+        return ranges
+            .iter()
+            .fold(1, |acc, curr| acc * ((curr.end) - (curr.start)));
+    }
+
+    let workflow = &workflows[workflow];
+
+    let mut sum = 0;
+    for rule in workflow.rules.iter() {
+        match rule {
+            Rule::AlwaysTrue(next) => {
+                sum += get_workflows_ranges(workflows, ranges, next);
+            }
+            Rule::Conditional {
+                attr,
+                operation,
+                value,
+                next,
+            } => {
+                let mut new_ranges = ranges.clone();
+
+                match operation {
+                    RuleOperation::LessThan => {
+                        ranges[attr.to_range_idx()].start = *value;
+                        new_ranges[attr.to_range_idx()].end = *value;
+                    }
+                    RuleOperation::GreaterThan => {
+                        ranges[attr.to_range_idx()].end = *value + 1;
+                        new_ranges[attr.to_range_idx()].start = *value + 1;
+                    }
+                };
+
+                sum += get_workflows_ranges(workflows, &mut new_ranges, next);
+            }
+        }
+    }
+
+    sum
+}
+
+impl Solution for Aplenty {
+    type Input = System;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        parse_input(reader)
+    }
+
+    fn part1(system: &Self::Input) -> Self::Part1 {
+        let workflows = &system.workflows;
+
+        system
+            .part_ratings
+            .iter()
+            .filter(|part_rating| {
+                let mut next_workflow_name = "in";
+
+                // parsing checks every workflow leads to `A`, `R` or another one
+                loop {
+                    match workflows[next_workflow_name].apply(part_rating) {
+                        "A" => return true,
+                        "R" => return false,
+                        next => next_workflow_name = next,
+                    };
+                }
+            })
+            .map(|part_rating| part_rating.x + part_rating.m + part_rating.a + part_rating.s)
+            .sum()
+    }
+
+    fn part2(system: &Self::Input) -> Self::Part2 {
+        get_workflows_ranges(
+            &system.workflows,
+            &mut [1..4001, 1..4001, 1..4001, 1..4001],
+            "in",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART: &str = "{x=1,m=2,a=3,s=4}";
+
+    #[test]
+    fn rejects_unknown_workflows() {
+        let err = Aplenty::parse_str(&format!("in{{a<5:px,A}}\n\n{PART}"))
+            .err()
+            .unwrap();
+
+        assert_eq!((err.line(), err.column()), (Some(1), 8));
+        assert_eq!(err.expected(), "known workflow, `A` or `R`");
+        assert_eq!(err.found(), Some("px"));
+
+        let err = Aplenty::parse_str(&format!("in{{a<5:A,qq}}\n\n{PART}"))
+            .err()
+            .unwrap();
+        assert_eq!((err.column(), err.found()), (10, Some("qq")));
+    }
+
+    #[test]
+    fn requires_an_in_workflow() {
+        let err = Aplenty::parse_str(&format!("px{{A}}\nqs{{R}}\n\n{PART}"))
+            .err()
+            .unwrap();
+
+        assert_eq!(err.line(), Some(3));
+        assert_eq!(err.expected(), "workflow `in`");
+    }
+
+    #[test]
+    fn requires_a_fallback() {
+        let err = Aplenty::parse_str(&format!("in{{a<5:A}}\n\n{PART}"))
+            .err()
+            .unwrap();
+
+        assert_eq!((err.line(), err.column()), (Some(1), 9));
+        assert_eq!(err.expected(), "`,` and a fallback workflow");
+    }
+
+    #[test]
+    fn follows_workflows_to_the_end() {
+        let system = Aplenty::parse_str(&format!("in{{a<5:px,R}}\npx{{A}}\n\n{PART}")).unwrap();
+
+        assert_eq!(Aplenty::part1(&system), 10);
+        assert_eq!(Aplenty::part2(&system), 4 * 4000 * 4000 * 4000);
+    }
+}
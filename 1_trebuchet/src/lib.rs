@@ -0,0 +1,115 @@
+use std::io::BufRead;
+
+use common::{Error, Solution};
+
+mod scanner;
+mod vocabulary;
+
+pub use scanner::{Explanation, Scanner, Token};
+pub use vocabulary::{Vocabulary, BUILTINS};
+
+pub struct Trebuchet;
+
+/// The calibration values of a whole document, summed for both parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    /// Reading digits only, as in part one.
+    pub digits: u64,
+    /// Reading digits and digit words, as in part two.
+    pub words: u64,
+}
+
+impl Calibration {
+    /// Scans every line of `reader` once for both parts, reading the words of
+    /// `vocabulary` in part two. Only one line is kept in memory, so
+    /// documents of any size can be calibrated.
+    pub fn read(reader: impl BufRead, vocabulary: &Vocabulary) -> Result<Self, Error> {
+        let digits = Scanner::new(Mode::Digits, &Vocabulary::new());
+        let words = Scanner::new(Mode::DigitsAndWords, vocabulary);
+        let mut calibration = Calibration {
+            digits: 0,
+            words: 0,
+        };
+
+        scanner::for_each_line(reader, |line| {
+            calibration.digits += u64::from(digits.calibration_value(line).unwrap_or(0));
+            calibration.words += u64::from(words.calibration_value(line).unwrap_or(0));
+        })?;
+
+        Ok(calibration)
+    }
+}
+
+/// What counts as a digit in a calibration line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only `0` to `9`, as in part one.
+    Digits,
+    /// Digits and the words of the vocabulary, as in part two.
+    DigitsAndWords,
+}
+
+/// Sums the calibration values of `lines`, reading digit words from
+/// `vocabulary` in [`Mode::DigitsAndWords`]. Lines without any digit add
+/// nothing, [`Scanner::explain`] shows which ones they are.
+pub fn calibrate(lines: &[String], mode: Mode, vocabulary: &Vocabulary) -> u64 {
+    let scanner = Scanner::new(mode, vocabulary);
+
+    lines
+        .iter()
+        .map(|line| u64::from(scanner.calibration_value(line.as_bytes()).unwrap_or(0)))
+        .sum()
+}
+
+impl Solution for Trebuchet {
+    type Input = Calibration;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    /// Reads English digit words, [`Calibration::read`] takes any vocabulary.
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        Calibration::read(reader, &Vocabulary::english())
+    }
+
+    fn part1(calibration: &Self::Input) -> Self::Part1 {
+        calibration.digits
+    }
+
+    fn part2(calibration: &Self::Input) -> Self::Part2 {
+        calibration.words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_sums_both_parts_in_one_pass() {
+        for input in [include_str!("../test.txt"), include_str!("../test2.txt")] {
+            let lines: Vec<String> = input.lines().map(str::to_string).collect();
+            let english = Vocabulary::english();
+
+            assert_eq!(
+                Calibration::read(input.as_bytes(), &english).unwrap(),
+                Calibration {
+                    digits: calibrate(&lines, Mode::Digits, &english),
+                    words: calibrate(&lines, Mode::DigitsAndWords, &english),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn read_uses_the_given_vocabulary() {
+        let calibration = Calibration::read("zwei1\nacht9neun".as_bytes(), &Vocabulary::german());
+
+        assert_eq!(
+            calibration.unwrap(),
+            Calibration {
+                digits: 11 + 99,
+                words: 21 + 89,
+            }
+        );
+    }
+}
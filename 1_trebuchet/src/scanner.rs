@@ -0,0 +1,409 @@
+use std::{collections::VecDeque, fmt::Display, io::BufRead};
+
+use common::Error;
+
+use crate::{Mode, Vocabulary};
+
+#[derive(Debug, Clone, Copy)]
+struct Output {
+    len: usize,
+    digit: u32,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    // full transition table, so scanning never follows failure links
+    next: [u32; 256],
+    // the token spelled by the path to this state, if any
+    own: Option<Output>,
+    // the nearest state on the failure chain with a token of its own
+    dict: u32,
+    // of all tokens ending in this state, the one starting first and the one
+    // starting last
+    longest: Option<Output>,
+    shortest: Option<Output>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            next: [0; 256],
+            own: None,
+            dict: 0,
+            longest: None,
+            shortest: None,
+        }
+    }
+}
+
+/// Finds the first and last digit of a line in a single pass, using an
+/// Aho-Corasick automaton compiled from the digits and, depending on the
+/// mode, the words of a vocabulary.
+///
+/// Tokens may overlap, so `eightwo` starts with 8 and ends with 2. When two
+/// tokens start at the same byte the longer one wins.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    states: Vec<State>,
+}
+
+impl Scanner {
+    pub fn new(mode: Mode, vocabulary: &Vocabulary) -> Self {
+        let mut scanner = Self {
+            states: vec![State::new()],
+        };
+
+        for (digit, c) in ('0'..='9').enumerate() {
+            scanner.insert(c.to_string().as_bytes(), digit as u32);
+        }
+
+        if mode == Mode::DigitsAndWords {
+            for (word, digit) in vocabulary.words() {
+                scanner.insert(word.as_bytes(), digit);
+            }
+        }
+
+        scanner.link();
+        scanner
+    }
+
+    // adds a token to the trie, where a transition to the root means none
+    fn insert(&mut self, token: &[u8], digit: u32) {
+        if token.is_empty() {
+            return;
+        }
+
+        let mut state = 0;
+        for &byte in token {
+            state = match self.states[state].next[byte as usize] {
+                0 => {
+                    self.states.push(State::new());
+                    let child = self.states.len() - 1;
+                    self.states[state].next[byte as usize] = child as u32;
+                    child
+                }
+                next => next as usize,
+            };
+        }
+
+        let output = Some(Output {
+            len: token.len(),
+            digit,
+        });
+        self.states[state].own = output;
+        self.states[state].longest = output;
+        self.states[state].shortest = output;
+    }
+
+    // turns the trie into a complete automaton, breadth first so failure
+    // states are always finished before the states pointing at them
+    fn link(&mut self) {
+        let mut fail = vec![0; self.states.len()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(state) = queue.pop_front() {
+            for byte in 0..256 {
+                let child = self.states[state].next[byte] as usize;
+                let fallback = if state == 0 {
+                    0
+                } else {
+                    self.states[fail[state]].next[byte]
+                };
+
+                if child == 0 {
+                    self.states[state].next[byte] = fallback;
+                    continue;
+                }
+
+                let fallback = fallback as usize;
+                fail[child] = fallback;
+
+                // tokens of the failure state are suffixes, so shorter than
+                // the one ending in `child` itself
+                let inherited = self.states[fallback].clone();
+                let child_state = &mut self.states[child];
+                child_state.dict = match inherited.own {
+                    Some(_) => fallback as u32,
+                    None => inherited.dict,
+                };
+                child_state.longest = child_state.longest.or(inherited.longest);
+                child_state.shortest = inherited.shortest.or(child_state.shortest);
+
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// Returns the first and last digit of `line`, or `None` if it has none.
+    pub fn scan(&self, line: &[u8]) -> Option<(u32, u32)> {
+        let mut state = 0;
+        let mut first: Option<(usize, u32)> = None;
+        let mut last: Option<(usize, u32)> = None;
+
+        for (end, &byte) in line.iter().enumerate() {
+            state = self.states[state].next[byte as usize] as usize;
+            let current = &self.states[state];
+
+            if let Some(Output { len, digit }) = current.longest {
+                let start = end + 1 - len;
+                if first.is_none_or(|(first_start, _)| start <= first_start) {
+                    first = Some((start, digit));
+                }
+            }
+
+            if let Some(Output { len, digit }) = current.shortest {
+                let start = end + 1 - len;
+                if last.is_none_or(|(last_start, _)| start >= last_start) {
+                    last = Some((start, digit));
+                }
+            }
+        }
+
+        Some((first?.1, last?.1))
+    }
+
+    /// Returns the calibration value of `line`, its first and last digit,
+    /// or `None` if it has no digit.
+    pub fn calibration_value(&self, line: &[u8]) -> Option<u32> {
+        let (first, last) = self.scan(line)?;
+
+        Some(10 * first + last)
+    }
+
+    /// Returns every token of `line`, ordered by where they end.
+    pub fn tokens(&self, line: &[u8]) -> Vec<Token> {
+        let mut tokens = vec![];
+        let mut state = 0;
+
+        for (end, &byte) in line.iter().enumerate() {
+            state = self.states[state].next[byte as usize] as usize;
+
+            let mut matched = state;
+            while matched != 0 {
+                let current = &self.states[matched];
+                if let Some(Output { len, digit }) = current.own {
+                    tokens.push(Token {
+                        start: end + 1 - len,
+                        end: end + 1,
+                        digit,
+                    });
+                }
+
+                matched = current.dict as usize;
+            }
+        }
+
+        tokens
+    }
+
+    /// Lists the tokens of `line` together with the digits they produce.
+    pub fn explain<'a>(&self, line: &'a [u8]) -> Explanation<'a> {
+        Explanation {
+            line,
+            tokens: self.tokens(line),
+        }
+    }
+
+    /// Sums the calibration values of every line of `reader` without
+    /// keeping more than one line in memory. Lines without a digit add
+    /// nothing.
+    pub fn calibrate(&self, reader: impl BufRead) -> Result<u64, Error> {
+        let mut sum = 0;
+        for_each_line(reader, |line| {
+            sum += u64::from(self.calibration_value(line).unwrap_or(0))
+        })?;
+
+        Ok(sum)
+    }
+}
+
+/// A digit or digit word found at the byte range `start..end` of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub digit: u32,
+}
+
+/// How the calibration value of a single line came about.
+#[derive(Debug, Clone)]
+pub struct Explanation<'a> {
+    line: &'a [u8],
+    tokens: Vec<Token>,
+}
+
+impl Explanation<'_> {
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    // same choice as `Scanner::scan`, the longer token wins a tie
+    pub fn first(&self) -> Option<Token> {
+        self.tokens
+            .iter()
+            .min_by_key(|token| (token.start, std::cmp::Reverse(token.end)))
+            .copied()
+    }
+
+    pub fn last(&self) -> Option<Token> {
+        self.tokens
+            .iter()
+            .max_by_key(|token| (token.start, token.end))
+            .copied()
+    }
+
+    pub fn value(&self) -> Option<u32> {
+        Some(10 * self.first()?.digit + self.last()?.digit)
+    }
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (Some(first), Some(last)) = (self.first(), self.last()) else {
+            return write!(f, "no digit");
+        };
+
+        for token in &self.tokens {
+            let text = String::from_utf8_lossy(&self.line[token.start..token.end]);
+            write!(f, "{text}@{} ", token.start)?;
+        }
+
+        write!(
+            f,
+            "=> first {}, last {}, value {}",
+            first.digit,
+            last.digit,
+            10 * first.digit + last.digit
+        )
+    }
+}
+
+// calls `f` with every line of `reader`, without its line ending, reusing a
+// single buffer
+pub(crate) fn for_each_line(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(&[u8]),
+) -> Result<(), Error> {
+    let mut line = Vec::new();
+
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        let content = line.strip_suffix(b"\n").unwrap_or(&line);
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+
+        f(content);
+        line.clear();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the longest token starting at each position, found by trying every
+    // token there
+    fn naive_scan(mode: Mode, vocabulary: &Vocabulary, line: &[u8]) -> Option<(u32, u32)> {
+        let digits: Vec<(String, u32)> = (0..10).map(|digit| (digit.to_string(), digit)).collect();
+        let words: Vec<(String, u32)> = match mode {
+            Mode::Digits => vec![],
+            Mode::DigitsAndWords => vocabulary
+                .words()
+                .map(|(word, digit)| (word.to_string(), digit))
+                .collect(),
+        };
+
+        let found: Vec<u32> = (0..line.len())
+            .filter_map(|start| {
+                digits
+                    .iter()
+                    .chain(&words)
+                    .filter(|(token, _)| line[start..].starts_with(token.as_bytes()))
+                    .max_by_key(|(token, _)| token.len())
+                    .map(|(_, digit)| *digit)
+            })
+            .collect();
+
+        Some((*found.first()?, *found.last()?))
+    }
+
+    // overlapping words, plus every line of up to five letters drawn from
+    // `one`, `two`, `eight` and `nine`
+    fn lines() -> Vec<Vec<u8>> {
+        let mut lines: Vec<Vec<u8>> = [
+            "eightwo",
+            "oneight",
+            "twone",
+            "sevenine",
+            "xtwonex",
+            "eighthree",
+            "1nineight",
+            "zoneightwone",
+            "ninenineine",
+            "oneoneone",
+            "treb7uchet",
+        ]
+        .iter()
+        .map(|line| line.as_bytes().to_vec())
+        .collect();
+
+        let alphabet = b"eghinotw1";
+        let mut shorter: Vec<Vec<u8>> = vec![vec![]];
+        for _ in 0..5 {
+            shorter = shorter
+                .iter()
+                .flat_map(|line| {
+                    alphabet.iter().map(move |&letter| {
+                        let mut longer = line.clone();
+                        longer.push(letter);
+                        longer
+                    })
+                })
+                .collect();
+            lines.extend(shorter.iter().cloned());
+        }
+
+        lines
+    }
+
+    #[test]
+    fn scan_matches_naive_scan() {
+        let mut prefixes = Vocabulary::english();
+        prefixes.insert("on", 4);
+        prefixes.insert("oneone", 6);
+
+        for (mode, vocabulary) in [
+            (Mode::Digits, Vocabulary::english()),
+            (Mode::DigitsAndWords, Vocabulary::english()),
+            (Mode::DigitsAndWords, prefixes),
+        ] {
+            let scanner = Scanner::new(mode, &vocabulary);
+
+            for line in lines() {
+                assert_eq!(
+                    scanner.scan(&line),
+                    naive_scan(mode, &vocabulary, &line),
+                    "{mode:?} on {:?}",
+                    String::from_utf8_lossy(&line)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn overlapping_words_count_twice() {
+        let scanner = Scanner::new(Mode::DigitsAndWords, &Vocabulary::english());
+
+        assert_eq!(scanner.calibration_value(b"eightwo"), Some(82));
+        assert_eq!(scanner.calibration_value(b"oneight"), Some(18));
+        assert_eq!(scanner.calibration_value(b"twone"), Some(21));
+    }
+
+    #[test]
+    fn calibrate_strips_line_endings() {
+        let scanner = Scanner::new(Mode::DigitsAndWords, &Vocabulary::english());
+        let input = "two1nine\r\nabc\neightwothree\n7pqrstsixteen";
+
+        assert_eq!(scanner.calibrate(input.as_bytes()).unwrap(), 29 + 83 + 76);
+    }
+}
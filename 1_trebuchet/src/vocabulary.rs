@@ -0,0 +1,223 @@
+use std::{fs::File, io::BufRead, io::BufReader, path::Path};
+
+use common::{parse_token, Error, ParseError};
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+/// Names accepted by [`Vocabulary::builtin`].
+pub const BUILTINS: [&str; 4] = ["en", "de", "fr", "es"];
+
+/// The words spelling out digits in a calibration document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn english() -> Self {
+        Self::from_words(&ENGLISH)
+    }
+
+    pub fn german() -> Self {
+        Self::from_words(&GERMAN)
+    }
+
+    pub fn french() -> Self {
+        Self::from_words(&FRENCH)
+    }
+
+    pub fn spanish() -> Self {
+        Self::from_words(&SPANISH)
+    }
+
+    /// Looks up a built-in vocabulary by its language code, see [`BUILTINS`].
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "en" => Some(Self::english()),
+            "de" => Some(Self::german()),
+            "fr" => Some(Self::french()),
+            "es" => Some(Self::spanish()),
+            _ => None,
+        }
+    }
+
+    // words for one to nine, in order
+    fn from_words(words: &[&str; 9]) -> Self {
+        Self {
+            words: words
+                .iter()
+                .zip(1..)
+                .map(|(word, digit)| (word.to_string(), digit))
+                .collect(),
+        }
+    }
+
+    /// Reads a vocabulary with one `<word> <digit>` pair per line. Blank
+    /// lines and lines starting with `#` are skipped.
+    pub fn parse(reader: impl BufRead) -> Result<Self, Error> {
+        let mut vocabulary = Self::new();
+
+        for (line_idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            let entry = line.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+
+            let (word, digit) =
+                parse_entry(&line, entry).map_err(|err| err.at_line(line_idx + 1))?;
+            vocabulary.insert(word, digit);
+        }
+
+        Ok(vocabulary)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    /// Adds `word` for `digit`, replacing an earlier spelling of the same word.
+    pub fn insert(&mut self, word: impl Into<String>, digit: u32) {
+        let word = word.into();
+
+        match self.words.iter_mut().find(|(known, _)| *known == word) {
+            Some(entry) => entry.1 = digit,
+            None => self.words.push((word, digit)),
+        }
+    }
+
+    /// Adds every word of `other`, for documents mixing several languages.
+    pub fn merge(&mut self, other: &Vocabulary) {
+        for (word, digit) in &other.words {
+            self.insert(word.clone(), *digit);
+        }
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+fn parse_entry(line: &str, entry: &str) -> Result<(String, u32), ParseError> {
+    let mut tokens = entry.split_whitespace();
+
+    let word = tokens.next().expect("entry is not blank");
+    if word.chars().any(|c| c.is_ascii_digit()) {
+        return Err(ParseError::at(line, word, "word without digits"));
+    }
+
+    let digit = tokens
+        .next()
+        .ok_or_else(|| ParseError::end_of(line, "digit"))?;
+    let value = parse_token::<u32>(line, digit, "digit")?;
+    if value > 9 {
+        return Err(ParseError::at(line, digit, "digit"));
+    }
+
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::at(line, extra, "end of line"));
+    }
+
+    Ok((word.to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<Vocabulary, ParseError> {
+        Vocabulary::parse(input.as_bytes()).map_err(|err| match err {
+            Error::Parse(err) => err,
+            Error::Io(err) => panic!("reading a string failed: {err}"),
+        })
+    }
+
+    #[test]
+    fn parse_skips_comments_and_blank_lines() {
+        let vocabulary =
+            parse("# digits in Dutch\n\neen 1\n  # indented comment\ntwee 2\n").unwrap();
+
+        assert_eq!(
+            vocabulary.words().collect::<Vec<_>>(),
+            [("een", 1), ("twee", 2)]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_values_above_nine() {
+        let err = parse("een 1\ntien 10").unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (Some(2), 6));
+        assert_eq!(err.expected(), "digit");
+        assert_eq!(err.found(), Some("10"));
+    }
+
+    #[test]
+    fn parse_rejects_words_with_digits() {
+        let err = parse("tw0 2").unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (Some(1), 1));
+        assert_eq!(err.expected(), "word without digits");
+    }
+
+    #[test]
+    fn parse_rejects_extra_tokens() {
+        let err = parse("een 1 2").unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (Some(1), 7));
+        assert_eq!(err.expected(), "end of line");
+
+        let err = parse("een").unwrap_err();
+        assert_eq!((err.column(), err.expected()), (4, "digit"));
+    }
+
+    #[test]
+    fn builtin_knows_every_listed_language() {
+        for name in BUILTINS {
+            let vocabulary = Vocabulary::builtin(name).unwrap();
+            let digits: Vec<u32> = vocabulary.words().map(|(_, digit)| digit).collect();
+
+            assert_eq!(digits, (1..=9).collect::<Vec<_>>(), "{name}");
+        }
+
+        assert_eq!(Vocabulary::builtin("de"), Some(Vocabulary::german()));
+        assert_eq!(Vocabulary::builtin("nl"), None);
+    }
+
+    #[test]
+    fn merge_keeps_the_later_spelling() {
+        let mut vocabulary = Vocabulary::english();
+        vocabulary.merge(&Vocabulary::french());
+
+        // `six` is spelled the same in both
+        assert_eq!(vocabulary.words().count(), 17);
+        assert!(vocabulary.words().any(|word| word == ("huit", 8)));
+
+        let mut custom = Vocabulary::new();
+        custom.insert("one", 7);
+        vocabulary.merge(&custom);
+        assert!(vocabulary.words().any(|word| word == ("one", 7)));
+        assert_eq!(vocabulary.words().count(), 17);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{Cubes, Game};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InferenceError {
+    /// A target id that none of the games has.
+    UnknownGame(usize),
+    /// A game outside the targets that is possible with every bag the
+    /// targets allow.
+    Unavoidable { game: usize },
+}
+
+impl Display for InferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InferenceError::UnknownGame(id) => write!(f, "there is no game {id}"),
+            InferenceError::Unavoidable { game } => write!(
+                f,
+                "game {game} is possible with every bag that allows the targets"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InferenceError {}
+
+/// The smallest bag with which every one of `games` is possible.
+pub fn infer_bag<'a>(games: impl IntoIterator<Item = &'a Game>) -> Cubes {
+    let mut bag = Cubes::new();
+    for game in games {
+        bag.include(&game.minimum_bag());
+    }
+
+    bag
+}
+
+/// Finds the smallest bag with which exactly the games in `targets` are
+/// possible.
+///
+/// Adding cubes to a bag never makes a game impossible, so if the smallest
+/// bag allowing the targets also allows another game, no bag can exclude it.
+pub fn find_bag(games: &[Game], targets: &HashSet<usize>) -> Result<Cubes, InferenceError> {
+    if let Some(id) = targets
+        .iter()
+        .filter(|id| !games.iter().any(|game| game.id() == **id))
+        .min()
+    {
+        return Err(InferenceError::UnknownGame(*id));
+    }
+
+    let bag = infer_bag(games.iter().filter(|game| targets.contains(&game.id())));
+
+    match games
+        .iter()
+        .find(|game| !targets.contains(&game.id()) && game.is_possible(&bag))
+    {
+        Some(game) => Err(InferenceError::Unavoidable { game: game.id() }),
+        None => Ok(bag),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn games(lines: &[&str]) -> Vec<Game> {
+        lines.iter().map(|line| line.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn infer_bag_covers_every_game() {
+        let games = games(&["Game 1: 3 red; 1 teal", "Game 2: 1 red, 4 teal"]);
+
+        assert_eq!(infer_bag(&games).to_string(), "3 red, 4 teal");
+        assert!(infer_bag([]).is_empty());
+    }
+
+    #[test]
+    fn find_bag_excludes_the_other_games() {
+        let games = games(&["Game 1: 3 red", "Game 2: 5 blue", "Game 3: 4 red"]);
+
+        let bag = find_bag(&games, &HashSet::from([1, 2])).unwrap();
+        assert_eq!(bag.to_string(), "3 red, 5 blue");
+        assert!(!games[2].is_possible(&bag));
+    }
+
+    #[test]
+    fn find_bag_reports_unknown_games() {
+        let games = games(&["Game 1: 3 red", "Game 3: 4 red"]);
+
+        assert_eq!(
+            find_bag(&games, &HashSet::from([1, 5, 2])),
+            Err(InferenceError::UnknownGame(2))
+        );
+    }
+
+    #[test]
+    fn find_bag_reports_unavoidable_games() {
+        let games = games(&["Game 1: 4 red", "Game 2: 2 red", "Game 3: 9 red"]);
+
+        let err = find_bag(&games, &HashSet::from([1])).unwrap_err();
+        assert_eq!(err, InferenceError::Unavoidable { game: 2 });
+        assert_eq!(
+            err.to_string(),
+            "game 2 is possible with every bag that allows the targets"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use std::{fmt::Display, io::BufRead, str::FromStr};
+
+use common::{parse_token, split_once, Error, ParseError, Solution};
+
+mod inference;
+mod simulator;
+
+pub use inference::{find_bag, infer_bag, InferenceError};
+pub use simulator::{draw_probability, game_probability, Rng, Simulator};
+
+pub struct CubeConundrum;
+
+/// The colors of the puzzle, in the order they make up the power of a bag.
+pub const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// A number of cubes per color, used both for a single draw and for the
+/// contents of a bag. Colors keep the order they were added in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: Vec<(String, usize)>,
+}
+
+impl Cubes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of `color` cubes, 0 for a color that is not listed.
+    pub fn get(&self, color: &str) -> usize {
+        self.counts
+            .iter()
+            .find(|(known, _)| known == color)
+            .map_or(0, |(_, amount)| *amount)
+    }
+
+    pub fn set(&mut self, color: &str, amount: usize) {
+        match self.counts.iter_mut().find(|(known, _)| known == color) {
+            Some(entry) => entry.1 = amount,
+            None => self.counts.push((color.to_string(), amount)),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.counts
+            .iter()
+            .map(|(color, amount)| (color.as_str(), *amount))
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().map(|(_, amount)| amount).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Whether a bag holding `self` has enough cubes for every color of
+    /// `draw`.
+    pub fn contains(&self, draw: &Cubes) -> bool {
+        draw.iter().all(|(color, amount)| amount <= self.get(color))
+    }
+
+    /// Raises every color to at least its amount in `other`.
+    pub fn include(&mut self, other: &Cubes) {
+        for (color, amount) in other.iter() {
+            if amount > self.get(color) {
+                self.set(color, amount);
+            }
+        }
+    }
+
+    /// The product of the amounts of `colors`.
+    pub fn power(&self, colors: &[&str]) -> usize {
+        colors.iter().map(|color| self.get(color)).product()
+    }
+
+    // parses `draw`, a slice of `source`, like `3 blue, 4 red`
+    fn parse_draw(source: &str, draw: &str) -> Result<Self, ParseError> {
+        let mut cubes = Cubes::new();
+
+        for cube_draw in draw.split(',') {
+            let cube_draw = cube_draw.trim();
+
+            let (amount, color) = cube_draw
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(source, cube_draw, "amount and color"))?;
+            let amount: usize = parse_token(source, amount, "number")?;
+
+            if color.is_empty() || !color.chars().all(char::is_alphabetic) {
+                return Err(ParseError::at(source, color, "color"));
+            }
+
+            if cubes.counts.iter().any(|(known, _)| known == color) {
+                return Err(ParseError::at(source, color, "each color once per draw"));
+            }
+
+            cubes.counts.push((color.to_string(), amount));
+        }
+
+        Ok(cubes)
+    }
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cubes::parse_draw(s, s)
+    }
+}
+
+impl Display for Cubes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, (color, amount)) in self.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{amount} {color}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, usize)> for Cubes {
+    fn from_iter<I: IntoIterator<Item = (S, usize)>>(iter: I) -> Self {
+        let mut cubes = Cubes::new();
+        for (color, amount) in iter {
+            cubes.set(&color.into(), amount);
+        }
+
+        cubes
+    }
+}
+
+/// The first draw of a game that takes more cubes of a color than the bag
+/// holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// 0-based index of the draw.
+    pub draw: usize,
+    pub color: String,
+    pub drawn: usize,
+    pub available: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    id: usize,
+    draws: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn new(id: usize, draws: Vec<Cubes>) -> Self {
+        Self { id, draws }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn draws(&self) -> &[Cubes] {
+        &self.draws
+    }
+
+    /// The smallest bag every draw of this game could have come from.
+    pub fn minimum_bag(&self) -> Cubes {
+        let mut bag = Cubes::new();
+        for draw in &self.draws {
+            bag.include(draw);
+        }
+
+        bag
+    }
+
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.draws.iter().all(|draw| bag.contains(draw))
+    }
+
+    pub fn violation(&self, bag: &Cubes) -> Option<Violation> {
+        self.draws.iter().enumerate().find_map(|(idx, draw)| {
+            draw.iter()
+                .find(|(color, amount)| *amount > bag.get(color))
+                .map(|(color, drawn)| Violation {
+                    draw: idx,
+                    color: color.to_string(),
+                    drawn,
+                    available: bag.get(color),
+                })
+        })
+    }
+
+    /// The power of the minimum bag, counting only `colors`.
+    pub fn power(&self, colors: &[&str]) -> usize {
+        self.minimum_bag().power(colors)
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, cubes) = split_once(s, ":")?;
+        let game_id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::at(s, game, "`Game <id>`"))?;
+        let id: usize = parse_token(s, game_id, "game id")?;
+
+        let draws = cubes
+            .split(';')
+            .map(|draw| Cubes::parse_draw(s, draw))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, draws })
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+
+        for (idx, draw) in self.draws.iter().enumerate() {
+            let separator = if idx > 0 { ";" } else { "" };
+            write!(f, "{separator} {draw}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Solution for CubeConundrum {
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                Ok(line?
+                    .parse()
+                    .map_err(|err: ParseError| err.at_line(line_idx + 1))?)
+            })
+            .collect()
+    }
+
+    fn part1(games: &Self::Input) -> Self::Part1 {
+        let bag = Cubes::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+
+        games
+            .iter()
+            .filter(|game| game.is_possible(&bag))
+            .map(Game::id)
+            .sum()
+    }
+
+    fn part2(games: &Self::Input) -> Self::Part2 {
+        games.iter().map(|game| game.power(&COLORS)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_keep_arbitrary_colors_in_order() {
+        let game: Game = "Game 4: 2 teal, 1 red; 5 magenta".parse().unwrap();
+
+        let colors: Vec<_> = game.draws()[0].iter().collect();
+        assert_eq!(colors, [("teal", 2), ("red", 1)]);
+        assert_eq!(game.draws()[1].get("magenta"), 5);
+        assert_eq!(game.draws()[1].get("teal"), 0);
+        assert_eq!(game.to_string(), "Game 4: 2 teal, 1 red; 5 magenta");
+    }
+
+    #[test]
+    fn rejects_invalid_draws() {
+        let err = "3 blue, 4 blue".parse::<Cubes>().unwrap_err();
+        assert_eq!(
+            (err.column(), err.expected()),
+            (11, "each color once per draw")
+        );
+
+        let err = "3 bl4e".parse::<Cubes>().unwrap_err();
+        assert_eq!((err.column(), err.expected()), (3, "color"));
+    }
+
+    #[test]
+    fn minimum_bag_starts_empty() {
+        let game: Game = "Game 1: 0 red; 3 teal".parse().unwrap();
+
+        let bag = game.minimum_bag();
+        assert_eq!(bag.to_string(), "3 teal");
+        assert_eq!(bag.get("red"), 0);
+        assert_eq!(game.power(&["teal"]), 3);
+        assert_eq!(game.power(&COLORS), 0);
+    }
+
+    #[test]
+    fn violation_names_the_first_draw_over_the_bag() {
+        let game: Game = "Game 1: 1 red; 2 teal, 9 red; 20 red".parse().unwrap();
+        let bag = Cubes::from_iter([("red", 5), ("teal", 2)]);
+
+        assert!(!game.is_possible(&bag));
+        assert_eq!(
+            game.violation(&bag),
+            Some(Violation {
+                draw: 1,
+                color: "red".to_string(),
+                drawn: 9,
+                available: 5,
+            })
+        );
+
+        let bag = Cubes::from_iter([("red", 20), ("teal", 2)]);
+        assert!(game.is_possible(&bag));
+        assert_eq!(game.violation(&bag), None);
+    }
+}
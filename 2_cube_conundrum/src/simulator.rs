@@ -0,0 +1,226 @@
+use std::ops::RangeInclusive;
+
+use num::{BigInt, BigRational, BigUint, One, Zero};
+
+use crate::{Cubes, Game};
+
+/// SplitMix64, small and stable so a seed always yields the same fixtures.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform number in `0..bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+
+        // reject the top values that would favour small results
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    pub fn in_range(&mut self, range: &RangeInclusive<usize>) -> usize {
+        let span = (range.end() - range.start()) as u64 + 1;
+
+        range.start() + self.below(span) as usize
+    }
+}
+
+/// Plays games against a bag: every draw takes a handful of cubes without
+/// replacement, and puts them back before the next one.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    bag: Cubes,
+    rng: Rng,
+}
+
+impl Simulator {
+    pub fn new(bag: Cubes, seed: u64) -> Self {
+        Self {
+            bag,
+            rng: Rng::new(seed),
+        }
+    }
+
+    /// Draws `size` cubes. Colors are listed in the order they first came
+    /// out of the bag.
+    ///
+    /// Panics if the bag holds fewer than `size` cubes.
+    pub fn draw(&mut self, size: usize) -> Cubes {
+        assert!(size <= self.bag.total(), "the bag holds fewer cubes");
+
+        let mut left: Vec<(&str, usize)> = self.bag.iter().collect();
+        let mut remaining = self.bag.total();
+        let mut draw = Cubes::new();
+
+        for _ in 0..size {
+            let mut pick = self.rng.below(remaining as u64) as usize;
+            let (color, amount) = left
+                .iter_mut()
+                .find(|(_, amount)| {
+                    if pick < *amount {
+                        return true;
+                    }
+
+                    pick -= *amount;
+                    false
+                })
+                .expect("pick is below the remaining cubes");
+
+            *amount -= 1;
+            remaining -= 1;
+            draw.set(color, draw.get(color) + 1);
+        }
+
+        draw
+    }
+
+    /// Plays a game of `draws` handfuls, each holding a number of cubes
+    /// picked uniformly from `sizes`.
+    ///
+    /// Panics if `sizes` allows empty handfuls, which the puzzle format
+    /// cannot express, or handfuls larger than the bag.
+    pub fn game(&mut self, id: usize, draws: usize, sizes: RangeInclusive<usize>) -> Game {
+        assert!(*sizes.start() > 0, "a handful holds at least one cube");
+
+        let draws = (0..draws)
+            .map(|_| {
+                let size = self.rng.in_range(&sizes);
+                self.draw(size)
+            })
+            .collect();
+
+        Game::new(id, draws)
+    }
+}
+
+fn binomial(n: usize, k: usize) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+
+    // C(n, k) = C(n, n - k), and every prefix of the product is itself a
+    // binomial, so the division is always exact
+    let k = k.min(n - k);
+    (1..=k).fold(BigUint::one(), |acc, i| acc * (n - k + i) / i)
+}
+
+/// The exact probability that a handful of `draw.total()` cubes from `bag`
+/// holds exactly the cubes of `draw`.
+pub fn draw_probability(bag: &Cubes, draw: &Cubes) -> BigRational {
+    let favourable = draw
+        .iter()
+        .map(|(color, amount)| binomial(bag.get(color), amount))
+        .product::<BigUint>();
+    let total = binomial(bag.total(), draw.total());
+
+    if total.is_zero() {
+        return BigRational::zero();
+    }
+
+    BigRational::new(BigInt::from(favourable), BigInt::from(total))
+}
+
+/// The exact probability that every draw of `game` comes out of `bag`, given
+/// the number of cubes in each handful.
+pub fn game_probability(bag: &Cubes, game: &Game) -> BigRational {
+    game.draws()
+        .iter()
+        .map(|draw| draw_probability(bag, draw))
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    fn cubes(s: &str) -> Cubes {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn binomial_matches_pascals_triangle() {
+        let row: Vec<BigUint> = (0..=5).map(|k| binomial(5, k)).collect();
+        let expected: Vec<BigUint> = [1u32, 5, 10, 10, 5, 1].map(BigUint::from).to_vec();
+
+        assert_eq!(row, expected);
+        assert_eq!(binomial(3, 4), BigUint::zero());
+    }
+
+    #[test]
+    fn draw_probability_is_hypergeometric() {
+        let bag = cubes("3 red, 1 blue");
+
+        assert_eq!(draw_probability(&bag, &cubes("2 red, 1 blue")), ratio(3, 4));
+        assert_eq!(draw_probability(&bag, &cubes("3 red")), ratio(1, 4));
+        assert_eq!(draw_probability(&bag, &cubes("1 blue")), ratio(1, 4));
+        assert_eq!(draw_probability(&bag, &cubes("2 blue")), ratio(0, 1));
+        assert_eq!(draw_probability(&bag, &cubes("1 green")), ratio(0, 1));
+    }
+
+    #[test]
+    fn draws_of_one_size_sum_to_one() {
+        let bag = cubes("4 red, 3 green, 2 blue");
+
+        let total: BigRational = (0..=4)
+            .flat_map(|red| (0..=3).map(move |green| (red, green)))
+            .filter(|(red, green)| red + green <= 5 && 5 - red - green <= 2)
+            .map(|(red, green)| {
+                let draw =
+                    Cubes::from_iter([("red", red), ("green", green), ("blue", 5 - red - green)]);
+                draw_probability(&bag, &draw)
+            })
+            .sum();
+
+        assert_eq!(total, ratio(1, 1));
+    }
+
+    #[test]
+    fn game_probability_multiplies_draws() {
+        let bag = cubes("1 red, 1 blue");
+        let game: Game = "Game 1: 1 red; 1 blue, 1 red".parse().unwrap();
+
+        assert_eq!(game_probability(&bag, &game), ratio(1, 2));
+    }
+
+    #[test]
+    fn same_seed_plays_the_same_games() {
+        let bag = cubes("12 red, 13 green, 14 blue");
+        let play = || {
+            let mut simulator = Simulator::new(bag.clone(), 2023);
+            (1..=5)
+                .map(|id| simulator.game(id, 3, 1..=10).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let games = play();
+        assert_eq!(games, play());
+
+        for game in &games {
+            assert_eq!(game.parse::<Game>().unwrap().to_string(), *game);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::{collections::HashMap, io::BufRead, ops::Range};
+
+use common::{Error, ParseError, Solution};
+use grid::{Grid, Position};
+use num::BigUint;
+
+mod rules;
+mod stream;
+
+pub use rules::{Aggregation, Rule, SymbolClass};
+pub use stream::{stream, Event};
+
+pub struct GearRatios;
+
+/// A number of the schematic, spanning `columns` of `row`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: usize,
+    pub row: usize,
+    pub columns: Range<usize>,
+}
+
+impl Number {
+    /// Every position touching the number, including diagonally. Positions
+    /// past the bottom and right edge are not filtered out.
+    pub fn border(&self) -> impl Iterator<Item = Position> + '_ {
+        let rows = self.row.saturating_sub(1)..=self.row + 1;
+        let columns = self.columns.start.saturating_sub(1)..=self.columns.end;
+
+        rows.flat_map(move |row| columns.clone().map(move |col| (row, col)))
+            .filter(|&(row, col)| row != self.row || !self.columns.contains(&col))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: char,
+    pub position: Position,
+}
+
+// finds the numbers and symbols of a single row
+fn scan_row(
+    row_idx: usize,
+    row: &[char],
+    symbol_class: &SymbolClass,
+) -> Result<(Vec<Number>, Vec<Symbol>), ParseError> {
+    let mut numbers = vec![];
+    let mut symbols = vec![];
+    let mut number: Option<Number> = None;
+
+    for (col, c) in row.iter().enumerate() {
+        match c.to_digit(10) {
+            Some(digit) => {
+                let current = number.get_or_insert(Number {
+                    value: 0,
+                    row: row_idx,
+                    columns: col..col,
+                });
+
+                current.value = current
+                    .value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit as usize))
+                    .ok_or_else(|| {
+                        ParseError::new(current.columns.start + 1, "smaller number", None)
+                            .at_line(row_idx + 1)
+                    })?;
+                current.columns.end = col + 1;
+            }
+            None => {
+                numbers.extend(number.take());
+
+                if symbol_class.contains(*c) {
+                    symbols.push(Symbol {
+                        kind: *c,
+                        position: (row_idx, col),
+                    });
+                }
+            }
+        }
+    }
+
+    numbers.extend(number);
+
+    Ok((numbers, symbols))
+}
+
+/// The numbers and symbols of a schematic, together with which of them touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    // the bipartite adjacency graph, as indices into `numbers` and `symbols`
+    symbols_of: Vec<Vec<usize>>,
+    numbers_of: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn new(grid: &Grid<char>, symbol_class: &SymbolClass) -> Result<Self, ParseError> {
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+
+        for (row_idx, row) in grid.rows().enumerate() {
+            let (row_numbers, row_symbols) = scan_row(row_idx, row, symbol_class)?;
+            numbers.extend(row_numbers);
+            symbols.extend(row_symbols);
+        }
+
+        let symbol_at: HashMap<Position, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(idx, symbol)| (symbol.position, idx))
+            .collect();
+
+        let mut symbols_of = vec![vec![]; numbers.len()];
+        let mut numbers_of = vec![vec![]; symbols.len()];
+
+        for (number_idx, number) in numbers.iter().enumerate() {
+            for symbol_idx in number.border().filter_map(|pos| symbol_at.get(&pos)) {
+                symbols_of[number_idx].push(*symbol_idx);
+                numbers_of[*symbol_idx].push(number_idx);
+            }
+        }
+
+        Ok(Self {
+            numbers,
+            symbols,
+            symbols_of,
+            numbers_of,
+        })
+    }
+
+    /// Reads a schematic from `reader`, treating `symbol_class` as symbols.
+    pub fn read(reader: impl BufRead, symbol_class: &SymbolClass) -> Result<Self, Error> {
+        Ok(Self::new(&Grid::parse(reader, Ok)?, symbol_class)?)
+    }
+
+    /// Every number, in reading order.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// Every symbol, in reading order.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// Indices of the symbols touching the number at index `number`.
+    pub fn symbols_of(&self, number: usize) -> &[usize] {
+        &self.symbols_of[number]
+    }
+
+    /// Indices of the numbers touching the symbol at index `symbol`.
+    pub fn numbers_of(&self, symbol: usize) -> &[usize] {
+        &self.numbers_of[symbol]
+    }
+
+    /// The numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(&self.symbols_of)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+    }
+
+    /// The symbols matching `rule`, with the numbers they touch.
+    pub fn matching<'a>(
+        &'a self,
+        rule: &'a Rule,
+    ) -> impl Iterator<Item = (&'a Symbol, Vec<&'a Number>)> {
+        self.symbols
+            .iter()
+            .zip(&self.numbers_of)
+            .filter(|(symbol, numbers)| rule.matches(symbol.kind, numbers.len()))
+            .map(|(symbol, numbers)| {
+                (
+                    symbol,
+                    numbers.iter().map(|idx| &self.numbers[*idx]).collect(),
+                )
+            })
+    }
+
+    /// Aggregates the numbers around each symbol matching `rule`, and sums
+    /// the results over all those symbols.
+    pub fn aggregate(&self, rule: &Rule) -> BigUint {
+        self.matching(rule)
+            .map(|(_, numbers)| rule.aggregation.apply(numbers))
+            .sum()
+    }
+}
+
+impl Solution for GearRatios {
+    type Input = Schematic;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        Schematic::read(reader, &SymbolClass::default())
+    }
+
+    fn part1(schematic: &Self::Input) -> Self::Part1 {
+        Aggregation::Sum.apply(schematic.part_numbers())
+    }
+
+    fn part2(schematic: &Self::Input) -> Self::Part2 {
+        schematic.aggregate(&Rule::GEAR_RATIO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schematic(rows: &str) -> Schematic {
+        Schematic::new(
+            &Grid::parse(rows.as_bytes(), Ok).unwrap(),
+            &SymbolClass::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn numbers_span_their_digits() {
+        let schematic = schematic("467..\n..*..\n.35.7");
+
+        let spans: Vec<_> = schematic
+            .numbers()
+            .iter()
+            .map(|number| (number.value, number.row, number.columns.clone()))
+            .collect();
+        assert_eq!(spans, [(467, 0, 0..3), (35, 2, 1..3), (7, 2, 4..5)]);
+    }
+
+    #[test]
+    fn border_surrounds_the_number() {
+        let number = Number {
+            value: 12,
+            row: 1,
+            columns: 1..3,
+        };
+
+        let mut border: Vec<_> = number.border().collect();
+        border.sort();
+        assert_eq!(
+            border,
+            [
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 0),
+                (1, 3),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (2, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn a_number_touching_two_symbols_belongs_to_both() {
+        let schematic = schematic("#....\n.42*.\n.....\n7....");
+
+        assert_eq!(schematic.symbols().len(), 2);
+        assert_eq!(schematic.symbols_of(0), [0, 1]);
+        assert_eq!(schematic.numbers_of(0), [0]);
+        assert_eq!(schematic.numbers_of(1), [0]);
+        assert!(schematic.symbols_of(1).is_empty());
+
+        let values: Vec<_> = schematic
+            .part_numbers()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, [42]);
+    }
+
+    #[test]
+    fn matching_counts_the_parts_of_each_symbol() {
+        let schematic = schematic("1.2..\n.*...\n3..*4\n.....");
+        let rule = |parts| Rule {
+            parts,
+            ..Rule::GEAR_RATIO
+        };
+
+        let gears: Vec<_> = schematic
+            .matching(&rule(Some(3)))
+            .map(|(gear, numbers)| {
+                let values: Vec<_> = numbers.iter().map(|number| number.value).collect();
+                (gear.position, values)
+            })
+            .collect();
+        assert_eq!(gears, [((1, 1), vec![1, 2, 3])]);
+        assert_eq!(schematic.matching(&rule(Some(1))).count(), 1);
+        assert_eq!(schematic.matching(&rule(Some(2))).count(), 0);
+        assert_eq!(schematic.matching(&rule(None)).count(), 2);
+    }
+}
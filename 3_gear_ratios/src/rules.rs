@@ -0,0 +1,237 @@
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
+
+use common::{parse_token, split_once, ParseError};
+use num::{BigUint, One, Zero};
+
+use crate::Number;
+
+/// Which characters of a schematic count as symbols.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SymbolClass {
+    /// Anything but a digit or `.`.
+    #[default]
+    Any,
+    /// ASCII punctuation other than `.`.
+    Punctuation,
+    Set(BTreeSet<char>),
+}
+
+impl SymbolClass {
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            SymbolClass::Any => c != '.' && !c.is_ascii_digit(),
+            SymbolClass::Punctuation => c != '.' && c.is_ascii_punctuation(),
+            SymbolClass::Set(set) => set.contains(&c),
+        }
+    }
+}
+
+/// `any`, `punctuation`, or the symbols themselves, like `*#+`.
+impl FromStr for SymbolClass {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "any" => Ok(SymbolClass::Any),
+            "punctuation" => Ok(SymbolClass::Punctuation),
+            "" => Err(ParseError::end_of(s, "symbol class")),
+            _ => match s
+                .char_indices()
+                .find(|(_, c)| *c == '.' || c.is_ascii_digit())
+            {
+                Some((idx, c)) => Err(ParseError::at_char(
+                    s[..idx].chars().count() + 1,
+                    c,
+                    "symbol",
+                )),
+                None => Ok(SymbolClass::Set(s.chars().collect())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Sum,
+    Product,
+    Count,
+}
+
+impl Aggregation {
+    /// Combines the values of `numbers`, which can grow past any machine
+    /// integer for a product.
+    pub fn apply<'a>(&self, numbers: impl IntoIterator<Item = &'a Number>) -> BigUint {
+        let values = numbers
+            .into_iter()
+            .map(|number| BigUint::from(number.value));
+
+        match self {
+            Aggregation::Sum => values.fold(BigUint::zero(), |sum, value| sum + value),
+            Aggregation::Product => values.fold(BigUint::one(), |product, value| product * value),
+            Aggregation::Count => BigUint::from(values.count()),
+        }
+    }
+}
+
+impl FromStr for Aggregation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sum" => Ok(Aggregation::Sum),
+            "product" => Ok(Aggregation::Product),
+            "count" => Ok(Aggregation::Count),
+            _ => Err(ParseError::at(s, s, "`sum`, `product` or `count`")),
+        }
+    }
+}
+
+/// Combines the numbers around every matching symbol, the way `*` gears
+/// multiply exactly two part numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// The symbol this rule is about, or every symbol for `None`.
+    pub symbol: Option<char>,
+    /// Only symbols touching exactly this many numbers match.
+    pub parts: Option<usize>,
+    pub aggregation: Aggregation,
+}
+
+impl Rule {
+    /// The gear ratio rule of the puzzle.
+    pub const GEAR_RATIO: Rule = Rule {
+        symbol: Some('*'),
+        parts: Some(2),
+        aggregation: Aggregation::Product,
+    };
+
+    pub fn matches(&self, symbol: char, parts: usize) -> bool {
+        self.symbol.is_none_or(|kind| kind == symbol) && self.parts.is_none_or(|n| n == parts)
+    }
+}
+
+/// `<symbol|any>[/<parts>]:<aggregation>`, so the gear ratio is `*/2:product`.
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (selector, aggregation) = split_once(s, ":")?;
+        let aggregation = aggregation
+            .parse()
+            .map_err(|_| ParseError::at(s, aggregation, "`sum`, `product` or `count`"))?;
+
+        let (symbol, parts) = match selector.split_once('/') {
+            Some((symbol, parts)) => (symbol, Some(parse_token(s, parts, "number of parts")?)),
+            None => (selector, None),
+        };
+
+        let mut chars = symbol.chars();
+        let symbol = match (symbol, chars.next(), chars.next()) {
+            ("any", _, _) => None,
+            (_, Some(c), None) if c != '.' && !c.is_ascii_digit() => Some(c),
+            _ => return Err(ParseError::at(s, symbol, "a symbol or `any`")),
+        };
+
+        Ok(Rule {
+            symbol,
+            parts,
+            aggregation,
+        })
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.symbol {
+            Some(symbol) => write!(f, "{symbol}")?,
+            None => write!(f, "any")?,
+        }
+
+        if let Some(parts) = self.parts {
+            write!(f, "/{parts}")?;
+        }
+
+        let aggregation = match self.aggregation {
+            Aggregation::Sum => "sum",
+            Aggregation::Product => "product",
+            Aggregation::Count => "count",
+        };
+
+        write!(f, ":{aggregation}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_symbol_classes() {
+        assert_eq!("any".parse(), Ok(SymbolClass::Any));
+        assert_eq!("punctuation".parse(), Ok(SymbolClass::Punctuation));
+        assert_eq!(
+            "*#*".parse(),
+            Ok(SymbolClass::Set(BTreeSet::from(['*', '#'])))
+        );
+
+        let err = "*#7".parse::<SymbolClass>().unwrap_err();
+        assert_eq!((err.column(), err.found()), (3, Some("7")));
+        let err = "".parse::<SymbolClass>().unwrap_err();
+        assert_eq!(err.expected(), "symbol class");
+    }
+
+    #[test]
+    fn symbol_classes_skip_digits_and_dots() {
+        let set = SymbolClass::Set(BTreeSet::from(['*', 'x']));
+
+        for class in [SymbolClass::Any, SymbolClass::Punctuation, set.clone()] {
+            assert!(class.contains('*'));
+            assert!(!class.contains('.'));
+            assert!(!class.contains('7'));
+        }
+
+        assert!(SymbolClass::Any.contains('x'));
+        assert!(!SymbolClass::Punctuation.contains('x'));
+        assert!(SymbolClass::Punctuation.contains('#'));
+        assert!(set.contains('x'));
+        assert!(!set.contains('#'));
+    }
+
+    #[test]
+    fn rules_round_trip() {
+        for rule in ["*/2:product", "any:sum", "#/3:count", "+:sum"] {
+            assert_eq!(rule.parse::<Rule>().unwrap().to_string(), rule);
+        }
+
+        assert_eq!("*/2:product".parse(), Ok(Rule::GEAR_RATIO));
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let err = "*/2:max".parse::<Rule>().unwrap_err();
+        assert_eq!((err.column(), err.found()), (5, Some("max")));
+
+        let err = "**:sum".parse::<Rule>().unwrap_err();
+        assert_eq!(err.expected(), "a symbol or `any`");
+
+        let err = "*/x:sum".parse::<Rule>().unwrap_err();
+        assert_eq!((err.column(), err.expected()), (3, "number of parts"));
+    }
+
+    #[test]
+    fn products_do_not_overflow() {
+        let number = Number {
+            value: usize::MAX,
+            row: 0,
+            columns: 0..20,
+        };
+
+        let product = Aggregation::Product.apply([&number, &number]);
+        assert_eq!(product, BigUint::from(usize::MAX).pow(2));
+        assert_eq!(
+            Aggregation::Sum.apply([&number, &number]),
+            BigUint::from(usize::MAX) * 2u8
+        );
+        assert_eq!(Aggregation::Count.apply([&number]), BigUint::from(1u8));
+    }
+}
@@ -0,0 +1,232 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use common::Error;
+use grid::Grid;
+use num::BigUint;
+
+use crate::{scan_row, Number, Rule, Symbol, SymbolClass};
+
+/// Something the streaming scan found out about the schematic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A number touching at least one symbol.
+    Part(Number),
+    /// A symbol matching `rules[rule]`, with the aggregation of the numbers
+    /// it touches.
+    Match {
+        rule: usize,
+        symbol: Symbol,
+        value: BigUint,
+    },
+}
+
+#[derive(Default)]
+struct Row {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+impl Row {
+    // the symbols in the columns around `number`, found by binary search
+    // since both are in column order
+    fn touches(&self, number: &Number) -> bool {
+        let first = self
+            .symbols
+            .partition_point(|symbol| symbol.position.1 + 1 < number.columns.start);
+
+        self.symbols
+            .get(first)
+            .is_some_and(|symbol| symbol.position.1 <= number.columns.end)
+    }
+
+    fn numbers_around(&self, col: usize) -> impl Iterator<Item = &Number> {
+        let first = self
+            .numbers
+            .partition_point(|number| number.columns.end < col);
+
+        self.numbers[first..]
+            .iter()
+            .take_while(move |number| number.columns.start <= col + 1)
+    }
+}
+
+/// Scans the schematic in `reader` one row at a time, emitting every part
+/// number and every symbol matching one of `rules` as soon as the row below
+/// it was read. Only three rows are kept at any time.
+///
+/// Produces the same parts and matches as [`Schematic`](crate::Schematic),
+/// in reading order.
+pub fn stream(
+    reader: impl BufRead,
+    symbol_class: &SymbolClass,
+    rules: &[Rule],
+    mut emit: impl FnMut(Event),
+) -> Result<(), Error> {
+    // only holds the latest row, to check every row has the same width
+    let mut latest: Grid<char> = Grid::default();
+    let mut window: VecDeque<Row> = VecDeque::from([Row::default(), Row::default()]);
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        latest
+            .parse_row(&line?, Ok)
+            .map_err(|err| err.at_line(line_idx + 1))?;
+        if latest.height() > 1 {
+            latest.pop_front_row();
+        }
+
+        let (numbers, symbols) = scan_row(line_idx, latest.row(0), symbol_class)?;
+        window.push_back(Row { numbers, symbols });
+
+        settle(&window, rules, &mut emit);
+        window.pop_front();
+    }
+
+    // the last row has nothing below it
+    window.push_back(Row::default());
+    settle(&window, rules, &mut emit);
+
+    Ok(())
+}
+
+// emits everything about the middle row of the window
+fn settle(window: &VecDeque<Row>, rules: &[Rule], emit: &mut impl FnMut(Event)) {
+    let row = &window[1];
+
+    for number in &row.numbers {
+        if window.iter().any(|other| other.touches(number)) {
+            emit(Event::Part(number.clone()));
+        }
+    }
+
+    for symbol in &row.symbols {
+        let numbers: Vec<&Number> = window
+            .iter()
+            .flat_map(|other| other.numbers_around(symbol.position.1))
+            .collect();
+
+        for (idx, rule) in rules.iter().enumerate() {
+            if rule.matches(symbol.kind, numbers.len()) {
+                emit(Event::Match {
+                    rule: idx,
+                    symbol: *symbol,
+                    value: rule.aggregation.apply(numbers.iter().copied()),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schematic;
+
+    type Matches = Vec<(usize, Symbol, BigUint)>;
+
+    fn rules() -> Vec<Rule> {
+        vec![
+            Rule::GEAR_RATIO,
+            "any:sum".parse().unwrap(),
+            "#/0:count".parse().unwrap(),
+        ]
+    }
+
+    fn streamed(input: &str) -> (Vec<Number>, Matches) {
+        let mut parts = vec![];
+        let mut matches = vec![];
+
+        stream(
+            input.as_bytes(),
+            &SymbolClass::default(),
+            &rules(),
+            |event| match event {
+                Event::Part(number) => parts.push(number),
+                Event::Match {
+                    rule,
+                    symbol,
+                    value,
+                } => matches.push((rule, symbol, value)),
+            },
+        )
+        .unwrap();
+
+        (parts, matches)
+    }
+
+    // the same events, in the same reading order, from the whole grid
+    fn from_schematic(input: &str) -> (Vec<Number>, Matches) {
+        let grid = Grid::parse_str(input, Ok).unwrap();
+        let schematic = Schematic::new(&grid, &SymbolClass::default()).unwrap();
+        let rules = rules();
+
+        let parts = schematic.part_numbers().cloned().collect();
+        let mut matches: Matches = rules
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, rule)| {
+                schematic
+                    .matching(rule)
+                    .map(move |(symbol, numbers)| (idx, *symbol, rule.aggregation.apply(numbers)))
+            })
+            .collect();
+        matches.sort_by_key(|(idx, symbol, _)| (symbol.position, *idx));
+
+        (parts, matches)
+    }
+
+    fn assert_agree(input: &str) -> (Vec<Number>, Matches) {
+        let streamed = streamed(input);
+        assert_eq!(streamed, from_schematic(input), "on\n{input}");
+
+        streamed
+    }
+
+    #[test]
+    fn agrees_with_schematic_on_example() {
+        let (parts, matches) = assert_agree(include_str!("../test.txt"));
+
+        assert_eq!(parts.iter().map(|number| number.value).sum::<usize>(), 4361);
+        let gear_ratio: BigUint = matches
+            .iter()
+            .filter(|(rule, _, _)| *rule == 0)
+            .map(|(_, _, value)| value)
+            .sum();
+        assert_eq!(gear_ratio, BigUint::from(467835u32));
+    }
+
+    #[test]
+    fn agrees_with_schematic_at_the_edges() {
+        // symbols in every corner and along every edge, numbers ending rows
+        let (parts, _) = assert_agree(
+            "*12..34#\n\
+             5......6\n\
+             ..*..*..\n\
+             7.....89\n\
+             #1....2*",
+        );
+        let values: Vec<usize> = parts.iter().map(|number| number.value).collect();
+        assert_eq!(values, [12, 34, 5, 6, 7, 89, 1, 2]);
+
+        assert_agree("9.........\n.........#\n..........\n#........9");
+        assert_agree("1..\n...\n..*");
+    }
+
+    #[test]
+    fn agrees_with_schematic_on_a_single_row() {
+        let (parts, matches) = assert_agree("12*34..5#.6");
+
+        let values: Vec<usize> = parts.iter().map(|number| number.value).collect();
+        assert_eq!(values, [12, 34, 5]);
+        assert!(matches.contains(&(
+            0,
+            Symbol {
+                kind: '*',
+                position: (0, 2)
+            },
+            BigUint::from(12u32 * 34)
+        )));
+
+        assert_agree("*");
+        assert_agree("42");
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+    str::FromStr,
+};
+
+use common::{parse_token, split_once, Error, ParseError, Solution};
+use num::{BigUint, One, Zero};
+
+mod number_set;
+
+pub use number_set::NumberSet;
+
+pub struct Scratchcards;
+
+/// The largest number a card may hold.
+pub const MAX_NUMBER: usize = 999;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: usize,
+    pub winning: NumberSet,
+    pub drawn: NumberSet,
+}
+
+impl Card {
+    /// How many drawn numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection_len(&self.drawn)
+    }
+
+    /// Doubles with every match after the first, which outgrows any machine
+    /// integer for cards with more than 64 matches.
+    pub fn points(&self) -> BigUint {
+        match self.matches() {
+            0 => BigUint::zero(),
+            matches => BigUint::one() << (matches - 1),
+        }
+    }
+}
+
+fn parse_numbers(line: &str, numbers: &str) -> Result<NumberSet, ParseError> {
+    let mut set = NumberSet::new();
+
+    for entry in numbers.split_whitespace() {
+        let number: usize = parse_token(line, entry, "number")?;
+
+        if number > MAX_NUMBER {
+            return Err(ParseError::at(
+                line,
+                entry,
+                format!("number up to {MAX_NUMBER}"),
+            ));
+        }
+
+        if !set.insert(number) {
+            return Err(ParseError::at(line, entry, "distinct number"));
+        }
+    }
+
+    Ok(set)
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (card, numbers) = split_once(s, ":")?;
+        let id = card
+            .strip_prefix("Card")
+            .map(str::trim_start)
+            .ok_or_else(|| ParseError::at(s, card, "`Card <id>`"))?;
+        let id = parse_token(s, id, "card id")?;
+
+        let (winning, drawn) =
+            split_once(numbers, "|").map_err(|_| ParseError::end_of(s, "`|`"))?;
+
+        Ok(Card {
+            id,
+            winning: parse_numbers(s, winning)?,
+            drawn: parse_numbers(s, drawn)?,
+        })
+    }
+}
+
+/// How many copies of a card the cascade ended up with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Copies {
+    pub id: usize,
+    /// Including the original card. Doubles with every card that wins a copy
+    /// of all the cards after it, so it outgrows any machine integer.
+    pub copies: BigUint,
+    /// The ids of the cards that won copies of this one and how many each of
+    /// them won, only filled in when tracing.
+    pub from: Vec<(usize, BigUint)>,
+}
+
+/// Plays every card in order of its id. A card with `n` matches wins one copy
+/// of each of the cards `id + 1` to `id + n` per copy of itself; ids past the
+/// end of the table win nothing.
+pub fn cascade(cards: &[Card], trace: bool) -> Vec<Copies> {
+    let mut order: Vec<usize> = (0..cards.len()).collect();
+    order.sort_by_key(|&idx| cards[idx].id);
+
+    let index_of: HashMap<usize, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(position, &idx)| (cards[idx].id, position))
+        .collect();
+
+    let mut copies: Vec<Copies> = order
+        .iter()
+        .map(|&idx| Copies {
+            id: cards[idx].id,
+            copies: BigUint::from(1u8),
+            from: vec![],
+        })
+        .collect();
+
+    for (position, &idx) in order.iter().enumerate() {
+        let card = &cards[idx];
+        let won = copies[position].copies.clone();
+
+        for id in card.id + 1..=card.id + card.matches() {
+            let Some(&target) = index_of.get(&id) else {
+                continue;
+            };
+
+            copies[target].copies += &won;
+            if trace {
+                copies[target].from.push((card.id, won.clone()));
+            }
+        }
+    }
+
+    copies
+}
+
+impl Solution for Scratchcards {
+    type Input = Vec<Card>;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        let mut ids = HashSet::new();
+
+        reader
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                let line = line?;
+                let card: Card = line
+                    .parse()
+                    .map_err(|err: ParseError| err.at_line(line_idx + 1))?;
+
+                if !ids.insert(card.id) {
+                    let (card_label, _) = split_once(&line, ":")?;
+                    return Err(ParseError::at(&line, card_label, "unique card id")
+                        .at_line(line_idx + 1)
+                        .into());
+                }
+
+                Ok(card)
+            })
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input) -> Self::Part1 {
+        cards.iter().map(Card::points).sum()
+    }
+
+    fn part2(cards: &Self::Input) -> Self::Part2 {
+        cascade(cards, false).iter().map(|card| &card.copies).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(lines: &[&str]) -> Vec<Card> {
+        lines.iter().map(|line| line.parse().unwrap()).collect()
+    }
+
+    fn counts(copies: &[Copies]) -> Vec<(usize, BigUint)> {
+        copies
+            .iter()
+            .map(|card| (card.id, card.copies.clone()))
+            .collect()
+    }
+
+    fn big(copies: &[(usize, u32)]) -> Vec<(usize, BigUint)> {
+        copies
+            .iter()
+            .map(|&(id, copies)| (id, BigUint::from(copies)))
+            .collect()
+    }
+
+    #[test]
+    fn winnings_past_the_last_card_are_lost() {
+        let cards = cards(&["Card 1: 1 2 | 1 2", "Card 2: 3 4 | 3 4 5"]);
+
+        assert_eq!(counts(&cascade(&cards, false)), big(&[(1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn missing_ids_win_nothing() {
+        // card 1 wins copies of 2 and 3, but there is no card 2
+        let cards = cards(&["Card 3: 7 | 8", "Card 1: 5 6 | 5 6", "Card 4: 9 | 9"]);
+
+        assert_eq!(
+            counts(&cascade(&cards, false)),
+            big(&[(1, 1), (3, 2), (4, 1)])
+        );
+    }
+
+    #[test]
+    fn tracing_records_who_won_each_copy() {
+        let cards = cards(&["Card 1: 1 2 | 1 2", "Card 2: 3 | 3", "Card 3: 4 | 5"]);
+
+        let copies = cascade(&cards, true);
+        let from: Vec<_> = copies.iter().map(|card| card.from.clone()).collect();
+        assert_eq!(from, [vec![], big(&[(1, 1)]), big(&[(1, 1), (2, 2)])]);
+
+        assert!(cascade(&cards, false)
+            .iter()
+            .all(|card| card.from.is_empty()));
+    }
+
+    #[test]
+    fn copies_grow_past_u64() {
+        // every card wins a copy of all the cards after it, doubling them
+        let lines: Vec<String> = (1..=70)
+            .map(|id| {
+                let numbers: Vec<String> = (1..=70 - id).map(|n| n.to_string()).collect();
+                let numbers = numbers.join(" ");
+                format!("Card {id}: {numbers} | {numbers}")
+            })
+            .collect();
+        let cards: Vec<Card> = lines.iter().map(|line| line.parse().unwrap()).collect();
+
+        let total = Scratchcards::part2(&cards);
+        assert_eq!(total, (BigUint::from(1u8) << 70u32) - 1u8);
+    }
+
+    #[test]
+    fn rejects_duplicate_numbers() {
+        let err = "Card 1: 1 2 | 3 1 3".parse::<Card>().unwrap_err();
+
+        assert_eq!(err.column(), 19);
+        assert_eq!(err.expected(), "distinct number");
+        assert_eq!(err.found(), Some("3"));
+    }
+
+    #[test]
+    fn rejects_numbers_out_of_range() {
+        let err = "Card 1: 1 1000 | 3".parse::<Card>().unwrap_err();
+
+        assert_eq!(err.column(), 11);
+        assert_eq!(err.found(), Some("1000"));
+    }
+
+    #[test]
+    fn points_do_not_overflow() {
+        let numbers = (1..=70)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let card: Card = format!("Card 1: {numbers} | {numbers}").parse().unwrap();
+
+        assert_eq!(card.matches(), 70);
+        assert_eq!(card.points(), BigUint::one() << 69);
+    }
+}
@@ -0,0 +1,107 @@
+/// A set of card numbers stored as a bitset, one bit per number up to the
+/// largest one inserted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NumberSet {
+    words: Vec<u64>,
+}
+
+impl NumberSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `number`, returning whether it was not in the set yet.
+    pub fn insert(&mut self, number: usize) -> bool {
+        let (word, bit) = (number / 64, 1 << (number % 64));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+
+        let is_new = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        is_new
+    }
+
+    pub fn contains(&self, number: usize) -> bool {
+        self.words
+            .get(number / 64)
+            .is_some_and(|word| word & (1 << (number % 64)) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// The number of elements in both sets.
+    pub fn intersection_len(&self, other: &NumberSet) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// The numbers in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(idx, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| idx * 64 + bit)
+        })
+    }
+}
+
+impl FromIterator<usize> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = NumberSet::new();
+        for number in iter {
+            set.insert(number);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_reports_duplicates() {
+        let mut set = NumberSet::new();
+
+        assert!(set.insert(5));
+        assert!(set.insert(130));
+        assert!(!set.insert(5));
+        assert!(!set.insert(130));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(130));
+        assert!(!set.contains(131));
+        assert!(!set.contains(10_000));
+    }
+
+    #[test]
+    fn intersection_len_handles_sets_of_different_sizes() {
+        let small: NumberSet = [1, 17, 63].into_iter().collect();
+        let large: NumberSet = [17, 63, 64, 500, 999].into_iter().collect();
+
+        assert_eq!(small.intersection_len(&large), 2);
+        assert_eq!(large.intersection_len(&small), 2);
+        assert_eq!(NumberSet::new().intersection_len(&large), 0);
+    }
+
+    #[test]
+    fn iter_is_ascending() {
+        let set: NumberSet = [999, 0, 64, 63].into_iter().collect();
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 999]);
+        assert!(NumberSet::new().is_empty());
+    }
+}
@@ -0,0 +1,690 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    io::BufRead,
+    ops::Range,
+    str::FromStr,
+};
+
+use common::{parse_token, split_once, Error, ParseError, Part, Solution};
+
+mod piecewise;
+
+pub use piecewise::PiecewiseMap;
+
+pub struct Fertilizer;
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    // the seeds read as `start length` pairs, or why they cannot be, which
+    // only matters to part two
+    seed_ranges: Result<Vec<Range<usize>>, ParseError>,
+    steps: Vec<ConversionStep>,
+    // indices into `steps`, keyed by the category they convert from
+    steps_from: HashMap<String, Vec<usize>>,
+    // lines read, so a missing route is reported past the last one
+    line_count: usize,
+    // the whole pipeline, composed once while parsing if the maps lead
+    // from seeds to locations
+    seed_to_location: Option<PiecewiseMap>,
+}
+
+/// One line of a map: `length` numbers starting at `source_start` convert to
+/// the numbers starting at `destination_start`.
+#[derive(Debug)]
+pub struct Conversion {
+    destination_start: usize,
+    source_start: usize,
+    range: usize,
+}
+
+impl Conversion {
+    fn source(&self) -> Range<usize> {
+        self.source_start..self.source_start + self.range
+    }
+
+    // only meaningful for values inside `source`
+    fn apply(&self, value: usize) -> usize {
+        value - self.source_start + self.destination_start
+    }
+}
+
+impl FromStr for Conversion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+        let mut next_number = |expected: &str| match split.next() {
+            Some(part) => parse_token::<usize>(s, part, expected).map(|number| (part, number)),
+            None => Err(ParseError::end_of(s, expected)),
+        };
+
+        let (_, destination_start) = next_number("destination start")?;
+        let (_, source_start) = next_number("source start")?;
+        let (range_token, range) = next_number("range")?;
+
+        // both ends are computed later, so neither may run past `usize::MAX`
+        if source_start.checked_add(range).is_none()
+            || destination_start.checked_add(range).is_none()
+        {
+            return Err(ParseError::at(
+                s,
+                range_token,
+                "range ending below `usize::MAX`",
+            ));
+        }
+
+        Ok(Conversion {
+            destination_start,
+            source_start,
+            range,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ConversionStep {
+    from: String,
+    to: String,
+    conversions: Vec<Conversion>,
+}
+
+impl ConversionStep {
+    // lines are paired with their line number for error reporting
+    fn from_lines(lines: &[(usize, String)]) -> Result<Self, ParseError> {
+        let mut lines = lines.iter();
+        let (line_no, header) = lines.next().expect("step has a header line");
+
+        let (name, _) = split_once(header, " ").map_err(|err| err.at_line(*line_no))?;
+        let (from, to) = split_once(name, "-to-")
+            .map_err(|_| ParseError::at(header, name, "`XYZ-to-XYZ`").at_line(*line_no))?;
+
+        Ok(Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            conversions: lines
+                .map(|(line_no, l)| l.parse().map_err(|err: ParseError| err.at_line(*line_no)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    /// This step as a piecewise map, where the first conversion covering a
+    /// number wins like in [`ConversionStep::convert`].
+    pub fn to_map(&self) -> PiecewiseMap {
+        let mut bounds: Vec<usize> = self
+            .conversions
+            .iter()
+            .flat_map(|conversion| [conversion.source_start, conversion.source().end])
+            .chain([0, usize::MAX])
+            .collect();
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let segments = bounds
+            .windows(2)
+            .map(|pair| {
+                let offset = self
+                    .conversions
+                    .iter()
+                    .find(|conversion| conversion.source().contains(&pair[0]))
+                    .map_or(0, |conversion| {
+                        conversion.destination_start as i128 - conversion.source_start as i128
+                    });
+
+                (pair[0] as i128, pair[1] as i128, Some(offset))
+            })
+            .collect();
+
+        PiecewiseMap::from_segments(segments)
+    }
+
+    pub fn convert(&self, value: usize) -> usize {
+        self.conversions
+            .iter()
+            .find(|conversion| conversion.source().contains(&value))
+            .map_or(value, |conversion| conversion.apply(value))
+    }
+
+    // maps whole intervals, splitting them wherever a conversion starts or
+    // ends; values no conversion covers keep their number
+    fn convert_ranges(&self, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+        let mut unconverted = ranges;
+        let mut converted = vec![];
+
+        for conversion in self.conversions.iter() {
+            let covered = conversion.source();
+            let mut rest = vec![];
+
+            for range in unconverted {
+                let overlap = range.start.max(covered.start)..range.end.min(covered.end);
+                if overlap.is_empty() {
+                    rest.push(range);
+                    continue;
+                }
+
+                converted.push(conversion.apply(overlap.start)..conversion.apply(overlap.end));
+
+                rest.extend(
+                    [range.start..overlap.start, overlap.end..range.end]
+                        .into_iter()
+                        .filter(|part| !part.is_empty()),
+                );
+            }
+
+            unconverted = rest;
+        }
+
+        converted.extend(unconverted);
+        merge(converted)
+    }
+}
+
+// sorts `ranges` and joins the ones overlapping or touching
+pub(crate) fn merge(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteError {
+    UnknownCategory(String),
+    /// No chain of maps leads from `from` to `to`.
+    Disconnected {
+        from: String,
+        to: String,
+    },
+}
+
+impl Display for RouteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RouteError::UnknownCategory(category) => write!(f, "no map mentions `{category}`"),
+            RouteError::Disconnected { from, to } => {
+                write!(f, "no maps lead from `{from}` to `{to}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[usize] {
+        &self.seeds
+    }
+
+    /// The seeds read as `start length` pairs, or why they cannot be.
+    pub fn seed_ranges(&self) -> Result<&[Range<usize>], &ParseError> {
+        self.seed_ranges.as_deref()
+    }
+
+    pub fn steps(&self) -> &[ConversionStep] {
+        &self.steps
+    }
+
+    fn knows(&self, category: &str) -> bool {
+        self.steps
+            .iter()
+            .any(|step| step.from == category || step.to == category)
+    }
+
+    /// The shortest chain of maps converting `from` into `to`.
+    pub fn route(&self, from: &str, to: &str) -> Result<Vec<&ConversionStep>, RouteError> {
+        for category in [from, to] {
+            if !self.knows(category) {
+                return Err(RouteError::UnknownCategory(category.to_string()));
+            }
+        }
+
+        // breadth first, remembering the step each category was reached by
+        let mut reached_by: HashMap<&str, Option<usize>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+
+            for &idx in self.steps_from.get(category).into_iter().flatten() {
+                let next = self.steps[idx].to.as_str();
+                if !reached_by.contains_key(next) {
+                    reached_by.insert(next, Some(idx));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !reached_by.contains_key(to) {
+            return Err(RouteError::Disconnected {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        }
+
+        let mut route = vec![];
+        let mut category = to;
+        while let Some(Some(idx)) = reached_by.get(category) {
+            route.push(&self.steps[*idx]);
+            category = &self.steps[*idx].from;
+        }
+        route.reverse();
+
+        Ok(route)
+    }
+
+    /// Converts `value` of category `from` into category `to`, like the
+    /// humidity of seed 79 with `convert("seed", "humidity", 79)`.
+    pub fn convert(&self, from: &str, to: &str, value: usize) -> Result<usize, RouteError> {
+        Ok(self
+            .route(from, to)?
+            .iter()
+            .fold(value, |value, step| step.convert(value)))
+    }
+
+    /// Converts intervals of category `from` into sorted, disjoint intervals
+    /// of category `to`.
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: Vec<Range<usize>>,
+    ) -> Result<Vec<Range<usize>>, RouteError> {
+        Ok(self
+            .route(from, to)?
+            .iter()
+            .fold(merge(ranges), |ranges, step| step.convert_ranges(ranges)))
+    }
+
+    /// Composes the maps leading from `from` to `to` into a single one.
+    pub fn compose(&self, from: &str, to: &str) -> Result<PiecewiseMap, RouteError> {
+        Ok(self
+            .route(from, to)?
+            .iter()
+            .fold(PiecewiseMap::identity(), |map, step| {
+                map.then(&step.to_map())
+            }))
+    }
+
+    pub fn seed_to_location(&self) -> Option<&PiecewiseMap> {
+        self.seed_to_location.as_ref()
+    }
+
+    // the composed pipeline, which `Fertilizer::check` ensures exists
+    fn pipeline(&self) -> &PiecewiseMap {
+        self.seed_to_location
+            .as_ref()
+            .expect("checked for a route before solving")
+    }
+
+    // every step is defined everywhere, so non-empty seed ranges always reach
+    // some location
+    fn lowest_location(&self, seeds: &[Range<usize>]) -> usize {
+        self.pipeline()
+            .image(seeds)
+            .first()
+            .map(|range| range.start)
+            .expect("non-empty seed ranges reach a location")
+    }
+
+    // adds `step`, refusing a second map between the same categories and maps
+    // that would make a category convert back into itself
+    fn add_step(&mut self, step: ConversionStep, header: &str) -> Result<(), ParseError> {
+        let name = header.split(' ').next().unwrap_or(header);
+
+        if self
+            .steps
+            .iter()
+            .any(|known| known.from == step.from && known.to == step.to)
+        {
+            return Err(ParseError::at(header, name, "map not listed before"));
+        }
+
+        if step.from == step.to || self.leads_to(&step.to, &step.from) {
+            return Err(ParseError::at(
+                header,
+                name,
+                format!("map that does not lead back to `{}`", step.from),
+            ));
+        }
+
+        self.steps_from
+            .entry(step.from.clone())
+            .or_default()
+            .push(self.steps.len());
+        self.steps.push(step);
+
+        Ok(())
+    }
+
+    fn leads_to(&self, from: &str, to: &str) -> bool {
+        let mut seen = HashSet::from([from]);
+        let mut queue = vec![from];
+
+        while let Some(category) = queue.pop() {
+            if category == to {
+                return true;
+            }
+
+            for &idx in self.steps_from.get(category).into_iter().flatten() {
+                if seen.insert(&self.steps[idx].to) {
+                    queue.push(&self.steps[idx].to);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl Solution for Fertilizer {
+    type Input = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        let mut lines = reader.lines().enumerate();
+
+        let seed_line = match lines.next() {
+            Some((_, line)) => line?,
+            None => String::new(),
+        };
+
+        let seeds: Vec<usize> = seed_line
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::at(&seed_line, &seed_line, "`seeds: `").at_line(1))?
+            .split(' ')
+            .map(|num| parse_token(&seed_line, num, "number").map_err(|err| err.at_line(1)))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+        let seed_ranges = seed_ranges(&seed_line, &seeds).map_err(|err| err.at_line(1));
+
+        let mut almanac = Almanac {
+            seeds,
+            seed_ranges,
+            steps: vec![],
+            steps_from: HashMap::new(),
+            line_count: 1,
+            seed_to_location: None,
+        };
+
+        while let Some((line_idx, line)) = lines.next() {
+            let line = line?;
+            almanac.line_count = line_idx + 1;
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut conv_lines: Vec<(usize, String)> = vec![(line_idx + 1, line)];
+            for (line_idx, line) in lines.by_ref() {
+                let line = line?;
+                almanac.line_count = line_idx + 1;
+                if line.is_empty() {
+                    break;
+                }
+
+                conv_lines.push((line_idx + 1, line));
+            }
+
+            let step = ConversionStep::from_lines(&conv_lines)?;
+            let (line_no, header) = &conv_lines[0];
+            almanac
+                .add_step(step, header)
+                .map_err(|err| err.at_line(*line_no))?;
+        }
+
+        almanac.seed_to_location = almanac.compose("seed", "location").ok();
+
+        Ok(almanac)
+    }
+
+    // other routes can be converted without one from seeds to locations, and
+    // part one reads the seeds one by one
+    fn check(almanac: &Self::Input, part: Part) -> Result<(), ParseError> {
+        if almanac.seed_to_location.is_none() {
+            return Err(
+                ParseError::new(1, "maps leading from `seed` to `location`", None)
+                    .at_line(almanac.line_count + 1),
+            );
+        }
+
+        match (part, &almanac.seed_ranges) {
+            (Part::Two, Err(err)) => Err(err.clone()),
+            _ => Ok(()),
+        }
+    }
+
+    fn part1(almanac: &Self::Input) -> Self::Part1 {
+        almanac
+            .seeds
+            .iter()
+            .filter_map(|&seed| almanac.pipeline().get(seed))
+            .min()
+            .expect("parsing guarantees at least one seed")
+    }
+
+    fn part2(almanac: &Self::Input) -> Self::Part2 {
+        let seeds = almanac
+            .seed_ranges
+            .as_ref()
+            .expect("checked the seed ranges before solving");
+
+        almanac.lowest_location(seeds)
+    }
+}
+
+// pairs up the seeds on `line` as `start length`, rejecting an odd count,
+// empty ranges and ranges running past `usize::MAX`
+fn seed_ranges(line: &str, seeds: &[usize]) -> Result<Vec<Range<usize>>, ParseError> {
+    if seeds.len() % 2 == 1 {
+        return Err(ParseError::end_of(line, "seed range length"));
+    }
+
+    let mut lengths = line.split(' ').skip(2).step_by(2);
+    seeds
+        .chunks(2)
+        .map(|pair| {
+            let token = lengths.next().expect("one length token per pair");
+            match pair[0].checked_add(pair[1]) {
+                Some(end) if pair[1] > 0 => Ok(pair[0]..end),
+                _ => Err(ParseError::at(
+                    line,
+                    token,
+                    "length of a non-empty range ending below `usize::MAX`",
+                )),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    // soil and water are not connected
+    const SPLIT: &str = "seeds: 1 2\n\nseed-to-soil map:\n0 1 5\n\nwater-to-light map:\n10 0 5";
+
+    #[test]
+    fn converts_along_the_route() {
+        let almanac = Fertilizer::parse_str(EXAMPLE).unwrap();
+
+        assert_eq!(almanac.convert("seed", "humidity", 79), Ok(78));
+        assert_eq!(almanac.convert("soil", "soil", 14), Ok(14));
+
+        let route: Vec<_> = almanac
+            .route("light", "location")
+            .unwrap()
+            .iter()
+            .map(|step| step.to())
+            .collect();
+        assert_eq!(route, ["temperature", "humidity", "location"]);
+    }
+
+    #[test]
+    fn reports_routes_that_do_not_exist() {
+        let almanac = Fertilizer::parse_str(SPLIT).unwrap();
+
+        assert_eq!(almanac.convert("water", "light", 3), Ok(13));
+        assert_eq!(
+            almanac.convert("soil", "seed", 3),
+            Err(RouteError::Disconnected {
+                from: "soil".to_string(),
+                to: "seed".to_string(),
+            })
+        );
+        assert_eq!(
+            almanac.convert("seed", "humus", 3),
+            Err(RouteError::UnknownCategory("humus".to_string()))
+        );
+    }
+
+    #[test]
+    fn solving_needs_a_route_to_locations() {
+        let almanac = Fertilizer::parse_str(SPLIT).unwrap();
+
+        let err = Fertilizer::check(&almanac, Part::One).unwrap_err();
+        assert_eq!(
+            (err.line(), err.expected()),
+            (Some(8), "maps leading from `seed` to `location`")
+        );
+        assert!(common::solve::<Fertilizer>(SPLIT.as_bytes(), &[Part::Two]).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_maps() {
+        let err = Fertilizer::parse_str("seeds: 1\n\na-to-b map:\n0 1 2\n\na-to-b map:\n5 6 7")
+            .err()
+            .unwrap();
+
+        assert_eq!((err.line(), err.column()), (Some(6), 1));
+        assert_eq!(err.expected(), "map not listed before");
+    }
+
+    #[test]
+    fn rejects_cyclic_maps() {
+        let err = Fertilizer::parse_str(
+            "seeds: 1\n\na-to-b map:\n0 1 2\n\nb-to-c map:\n0 1 2\n\nc-to-a map:\n0 1 2",
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!((err.line(), err.found()), (Some(9), Some("c-to-a")));
+        assert_eq!(err.expected(), "map that does not lead back to `c`");
+
+        let err = Fertilizer::parse_str("seeds: 1\n\na-to-a map:\n0 1 2")
+            .err()
+            .unwrap();
+        assert_eq!(err.line(), Some(3));
+    }
+
+    // every start up to 120 in steps of 7, with lengths from a single seed to
+    // wider than any conversion
+    fn ranges() -> Vec<Range<usize>> {
+        (0..120)
+            .step_by(7)
+            .flat_map(|start| [1, 2, 5, 13, 40, 100].map(|length| start..start + length))
+            .collect()
+    }
+
+    #[test]
+    fn composed_map_agrees_with_stepwise_conversion() {
+        let almanac = Fertilizer::parse_str(EXAMPLE).unwrap();
+        let map = almanac.seed_to_location().unwrap();
+        let inverse = map.inverse().expect("the example maps are bijections");
+
+        for seed in 0..200 {
+            let location = almanac.convert("seed", "location", seed).unwrap();
+            assert_eq!(map.get(seed), Some(location), "seed {seed}");
+            assert_eq!(inverse.get(location), Some(seed), "location {location}");
+            assert_eq!(map.preimage(location..location + 1), vec![seed..seed + 1]);
+        }
+    }
+
+    #[test]
+    fn composed_map_agrees_with_stepwise_range_conversion() {
+        let almanac = Fertilizer::parse_str(EXAMPLE).unwrap();
+        let map = almanac.seed_to_location().unwrap();
+        let inverse = map.inverse().unwrap();
+
+        for range in ranges() {
+            let converted = almanac
+                .convert_ranges("seed", "location", vec![range.clone()])
+                .unwrap();
+            assert_eq!(
+                map.image(std::slice::from_ref(&range)),
+                converted,
+                "seeds {range:?}"
+            );
+
+            let seeds = map.preimage(range.clone());
+            assert_eq!(
+                inverse.image(std::slice::from_ref(&range)),
+                seeds,
+                "locations {range:?}"
+            );
+            for location in almanac.convert_ranges("seed", "location", seeds).unwrap() {
+                assert!(range.start <= location.start && location.end <= range.end);
+            }
+        }
+    }
+
+    #[test]
+    fn part_one_reads_any_seed_list() {
+        for (seeds, lowest) in [("79 14 55", "43"), ("5 0 3 4", "22")] {
+            let input = EXAMPLE.replacen("79 14 55 13", seeds, 1);
+            let answers = common::solve::<Fertilizer>(input.as_bytes(), &[Part::One]).unwrap();
+            assert_eq!(answers, [lowest], "seeds {seeds}");
+        }
+    }
+
+    #[test]
+    fn part_two_rejects_odd_seed_counts() {
+        let almanac = Fertilizer::parse_str(&EXAMPLE.replacen(" 13", "", 1)).unwrap();
+
+        let err = Fertilizer::check(&almanac, Part::Two).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), 16));
+        assert_eq!(err.expected(), "seed range length");
+        assert_eq!(almanac.seed_ranges(), Err(&err));
+    }
+
+    #[test]
+    fn part_two_rejects_seed_ranges_that_overflow_or_are_empty() {
+        let input = EXAMPLE.replacen("55 13", &format!("{} 2", usize::MAX), 1);
+        let almanac = Fertilizer::parse_str(&input).unwrap();
+        let err = Fertilizer::check(&almanac, Part::Two).unwrap_err();
+        assert_eq!((err.line(), err.found()), (Some(1), Some("2")));
+
+        let almanac = Fertilizer::parse_str(&EXAMPLE.replacen("14", "0", 1)).unwrap();
+        let err = Fertilizer::check(&almanac, Part::Two).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), 11));
+        assert_eq!(Fertilizer::check(&almanac, Part::One), Ok(()));
+    }
+
+    #[test]
+    fn rejects_conversions_that_overflow() {
+        let input = format!(
+            "seeds: 79 14\n\nseed-to-location map:\n{} 0 2\n",
+            usize::MAX - 1
+        );
+        let err = Fertilizer::parse_str(&input).err().unwrap();
+        assert_eq!(err.line(), Some(4));
+        assert_eq!(err.found(), Some("2"));
+    }
+}
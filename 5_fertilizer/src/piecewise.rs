@@ -0,0 +1,172 @@
+use std::ops::Range;
+
+use crate::merge;
+
+const END: i128 = usize::MAX as i128;
+
+/// A map from numbers to numbers that adds a constant to every number of a
+/// segment. The segments partition `0..usize::MAX`, so lookups are a binary
+/// search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    // segment `i` covers `starts[i]..starts[i + 1]`, the last one runs up to
+    // `usize::MAX`
+    starts: Vec<usize>,
+    // added to every number of the segment, `None` where the map is undefined
+    offsets: Vec<Option<i128>>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self {
+            starts: vec![0],
+            offsets: vec![Some(0)],
+        }
+    }
+
+    // builds a map from disjoint segments, leaving the gaps between them
+    // undefined and joining neighbours with the same offset
+    pub(crate) fn from_segments(mut segments: Vec<(i128, i128, Option<i128>)>) -> Self {
+        segments.retain(|(start, end, _)| start < end);
+        segments.sort_by_key(|(start, _, _)| *start);
+
+        let mut map = Self {
+            starts: vec![],
+            offsets: vec![],
+        };
+        let mut push = |start: i128, offset: Option<i128>| {
+            if map.offsets.last() != Some(&offset) {
+                map.starts.push(start as usize);
+                map.offsets.push(offset);
+            }
+        };
+
+        let mut covered = 0;
+        for (start, end, offset) in segments {
+            if start > covered {
+                push(covered, None);
+            }
+
+            push(start, offset);
+            covered = end;
+        }
+
+        if covered < END {
+            push(covered, None);
+        }
+
+        map
+    }
+
+    /// Every segment with the offset its numbers are shifted by.
+    pub fn segments(&self) -> impl Iterator<Item = (Range<usize>, Option<i128>)> + '_ {
+        self.starts
+            .iter()
+            .zip(self.starts.iter().skip(1).chain([&usize::MAX]))
+            .zip(&self.offsets)
+            .map(|((&start, &end), &offset)| (start..end, offset))
+    }
+
+    // like `segments`, in wide numbers so offsets can be added freely
+    fn wide_segments(&self) -> impl Iterator<Item = (i128, i128, Option<i128>)> + '_ {
+        self.segments()
+            .map(|(range, offset)| (range.start as i128, range.end as i128, offset))
+    }
+
+    pub fn get(&self, value: usize) -> Option<usize> {
+        let segment = self.starts.partition_point(|&start| start <= value) - 1;
+        let offset = self.offsets[segment]?;
+
+        usize::try_from(value as i128 + offset).ok()
+    }
+
+    /// The map applying `self` first and `next` to its result.
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut segments = vec![];
+
+        for (start, end, offset) in self.wide_segments() {
+            let Some(offset) = offset else {
+                segments.push((start, end, None));
+                continue;
+            };
+
+            let image = (start + offset).max(0)..(end + offset).min(END);
+            for (next_start, next_end, next_offset) in next.wide_segments() {
+                let overlap = image.start.max(next_start)..image.end.min(next_end);
+                if !overlap.is_empty() {
+                    segments.push((
+                        overlap.start - offset,
+                        overlap.end - offset,
+                        next_offset.map(|next_offset| offset + next_offset),
+                    ));
+                }
+            }
+        }
+
+        Self::from_segments(segments)
+    }
+
+    /// The map taking every result back to the number it came from, if no
+    /// two numbers share a result.
+    pub fn inverse(&self) -> Option<PiecewiseMap> {
+        let mut segments: Vec<(i128, i128, Option<i128>)> = self
+            .wide_segments()
+            .filter_map(|(start, end, offset)| {
+                let offset = offset?;
+                Some((
+                    (start + offset).max(0),
+                    (end + offset).min(END),
+                    Some(-offset),
+                ))
+            })
+            .filter(|(start, end, _)| start < end)
+            .collect();
+
+        segments.sort_by_key(|(start, _, _)| *start);
+        if segments.windows(2).any(|pair| pair[0].1 > pair[1].0) {
+            return None;
+        }
+
+        Some(Self::from_segments(segments))
+    }
+
+    /// The sorted, disjoint results of every number in `ranges`.
+    pub fn image(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        let mut found = vec![];
+
+        for range in ranges {
+            for (start, end, offset) in self.wide_segments() {
+                let Some(offset) = offset else {
+                    continue;
+                };
+
+                let overlap = start.max(range.start as i128)..end.min(range.end as i128);
+                let mapped = (overlap.start + offset).max(0)..(overlap.end + offset).min(END);
+                if !mapped.is_empty() {
+                    found.push(mapped.start as usize..mapped.end as usize);
+                }
+            }
+        }
+
+        merge(found)
+    }
+
+    /// The sorted, disjoint numbers whose result lies in `range`.
+    pub fn preimage(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut found = vec![];
+
+        for (start, end, offset) in self.wide_segments() {
+            let Some(offset) = offset else {
+                continue;
+            };
+
+            let image = (start + offset)..(end + offset);
+            let overlap = image.start.max(range.start as i128)..image.end.min(range.end as i128);
+            if !overlap.is_empty() {
+                found.push((overlap.start - offset) as usize..(overlap.end - offset) as usize);
+            }
+        }
+
+        merge(found)
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    io::{self, BufRead},
+    ops::RangeInclusive,
+};
+
+use common::{parse_token, Error, ParseError, Part, Solution};
+
+pub struct BoatRace;
+
+pub struct RaceSheet {
+    times: Vec<u128>,
+    distance_records: Vec<u128>,
+    // the single race you get when ignoring the spaces between the numbers,
+    // as time and distance record, or why its numbers do not fit; only part
+    // two needs it
+    merged: Result<(u128, u128), ParseError>,
+}
+
+fn extract_numbers(line: &str) -> Result<u128, ParseError> {
+    line.split_whitespace()
+        .skip(1)
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::at(line, line, "number fitting the merged race"))
+}
+
+fn extract_races(line: &str, label: &str) -> Result<Vec<u128>, ParseError> {
+    let mut split = line.split_whitespace();
+
+    match split.next() {
+        Some(token) if token == label => {}
+        Some(token) => return Err(ParseError::at(line, token, format!("`{label}`"))),
+        None => return Err(ParseError::end_of(line, format!("`{label}`"))),
+    }
+
+    split
+        .map(|number| parse_token(line, number, "number"))
+        .collect()
+}
+
+fn parse_sheet_line(
+    line_no: usize,
+    line: Option<io::Result<String>>,
+    label: &str,
+) -> Result<(Vec<u128>, Result<u128, ParseError>), Error> {
+    let line = line.unwrap_or_else(|| Ok(String::new()))?;
+
+    let races = extract_races(&line, label).map_err(|err| err.at_line(line_no))?;
+    let merged = extract_numbers(&line).map_err(|err| err.at_line(line_no));
+
+    Ok((races, merged))
+}
+
+/// The hold times that beat `distance_record` in a race lasting `time`, or
+/// `None` if the record cannot be beaten.
+pub fn winning_hold_times(time: u128, distance_record: u128) -> Option<RangeInclusive<u128>> {
+    // a distance too large for `u128` beats any record
+    let beats = |hold: u128| {
+        hold.checked_mul(time - hold)
+            .is_none_or(|distance| distance > distance_record)
+    };
+
+    // the distance grows up to half the race, so the first winning hold time
+    // is found by binary search
+    let half = time / 2;
+    if !beats(half) {
+        return None;
+    }
+
+    let (mut low, mut high) = (0, half);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if beats(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    // the distance is symmetric around half the race
+    Some(low..=time - low)
+}
+
+fn calc_win_strategy_amount(time: u128, distance_record: u128) -> u128 {
+    winning_hold_times(time, distance_record).map_or(0, |holds| holds.end() - holds.start() + 1)
+}
+
+impl Solution for BoatRace {
+    type Input = RaceSheet;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        let mut lines = reader.lines();
+
+        let (times, time) = parse_sheet_line(1, lines.next(), "Time:")?;
+        let (distance_records, distance_record) = parse_sheet_line(2, lines.next(), "Distance:")?;
+
+        Ok(RaceSheet {
+            times,
+            distance_records,
+            merged: time.and_then(|time| Ok((time, distance_record?))),
+        })
+    }
+
+    fn check(sheet: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match (part, &sheet.merged) {
+            (Part::Two, Err(err)) => Err(err.clone()),
+            _ => Ok(()),
+        }
+    }
+
+    fn part1(sheet: &Self::Input) -> Self::Part1 {
+        sheet
+            .times
+            .iter()
+            .zip(sheet.distance_records.iter())
+            .map(|(&time, &distance_record)| calc_win_strategy_amount(time, distance_record))
+            .product()
+    }
+
+    fn part2(sheet: &Self::Input) -> Self::Part2 {
+        let (time, distance_record) = *sheet
+            .merged
+            .as_ref()
+            .expect("checked the merged race before solving");
+
+        calc_win_strategy_amount(time, distance_record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every hold time that beats the record, tried one by one
+    fn brute_force(time: u128, distance_record: u128) -> Vec<u128> {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > distance_record)
+            .collect()
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        for time in 0..60 {
+            for distance_record in 0..=time * time / 4 + 2 {
+                let expected = brute_force(time, distance_record);
+                let holds = winning_hold_times(time, distance_record);
+
+                assert_eq!(
+                    holds.map(Iterator::collect::<Vec<_>>).unwrap_or_default(),
+                    expected,
+                    "time {time}, record {distance_record}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn handles_races_past_u64() {
+        let time = u128::from(u64::MAX);
+        let (half, rest) = (time / 2, time - time / 2);
+
+        assert_eq!(winning_hold_times(time, 0), Some(1..=time - 1));
+        assert_eq!(winning_hold_times(time, half * rest - 1), Some(half..=rest));
+        assert_eq!(winning_hold_times(time, half * rest), None);
+
+        assert_eq!(
+            winning_hold_times(u128::MAX, u128::MAX),
+            Some(2..=u128::MAX - 2)
+        );
+    }
+
+    #[test]
+    fn merges_races_past_u64() {
+        let input = "Time: 71530 94000 10000 50000 70000\nDistance: 1 2 3 4 5";
+        let answers = common::solve::<BoatRace>(input.as_bytes(), &[Part::One, Part::Two]);
+
+        let product: u128 = 71529 * 93999 * 9999 * 49999 * 69999;
+        let merged: u128 = 7153094000100005000070000 - 1;
+        assert_eq!(answers.unwrap(), [product.to_string(), merged.to_string()]);
+    }
+
+    #[test]
+    fn part_one_ignores_a_merged_race_that_does_not_fit() {
+        let time = 10u128.pow(38);
+        let input = format!("Time: 3 {time}\nDistance: 1 1");
+        let sheet = BoatRace::parse_str(&input).unwrap();
+
+        assert_eq!(BoatRace::part1(&sheet), 2 * (time - 1));
+
+        let err = BoatRace::check(&sheet, Part::Two).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(err.expected(), "number fitting the merged race");
+        assert!(common::solve::<BoatRace>(input.as_bytes(), &[Part::Two]).is_err());
+    }
+}
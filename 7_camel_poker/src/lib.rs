@@ -1,10 +1,8 @@
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader, Error},
-    str::FromStr,
-};
+use std::{cmp::Ordering, collections::HashMap, io::BufRead, str::FromStr};
+
+use common::{parse_token, split_once, Error, ParseError, Solution};
+
+pub struct CamelPoker;
 
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq)]
 enum WinType {
@@ -18,21 +16,23 @@ enum WinType {
 }
 
 #[derive(Debug)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
     bid: usize,
-    win_type: WinType,
 }
 
-fn get_win_type(cards: &[Card]) -> WinType {
+fn get_win_type(cards: &[Card], jokers: bool) -> WinType {
     let mut card_counts: HashMap<&Card, usize> = HashMap::new();
 
     for card in cards {
         *card_counts.entry(card).or_insert(0) += 1;
     }
 
-    let joker_count = *card_counts.get(&Card::Jack).unwrap_or(&0);
-    card_counts.remove_entry(&Card::Jack);
+    let joker_count = if jokers {
+        card_counts.remove(&Card::Jack).unwrap_or(0)
+    } else {
+        0
+    };
 
     if card_counts.values().any(|v| (*v + joker_count) == 5) {
         return WinType::FiveOfAKind;
@@ -95,25 +95,29 @@ fn get_win_type(cards: &[Card]) -> WinType {
 }
 
 impl FromStr for Hand {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (cards, bid) = s.split_once(' ').expect("hand bid");
+        let (cards, bid) = split_once(s, " ")?;
+
+        if cards.chars().count() != 5 {
+            return Err(ParseError::at(s, cards, "5 cards"));
+        }
 
-        let cards: Vec<Card> = cards.chars().map(|char| char.into()).collect();
-        let win_type = get_win_type(&cards);
+        let cards: Vec<Card> = cards
+            .char_indices()
+            .map(|(idx, char)| Card::try_from(char).map_err(|err| err.shifted(idx)))
+            .collect::<Result<_, _>>()?;
 
         Ok(Self {
             cards,
-            bid: bid.parse().expect("number"),
-            win_type,
+            bid: parse_token(s, bid, "bid")?,
         })
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Card {
-    Jack,
     Two,
     Three,
     Four,
@@ -123,14 +127,26 @@ enum Card {
     Eight,
     Nine,
     Ten,
+    Jack,
     Queen,
     King,
     Ace,
 }
 
-impl From<char> for Card {
-    fn from(c: char) -> Self {
-        match c {
+impl Card {
+    fn strength(&self, jokers: bool) -> usize {
+        match self {
+            Card::Jack if jokers => 0,
+            card => *card as usize + 1,
+        }
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
             '2' => Self::Two,
             '3' => Self::Three,
             '4' => Self::Four,
@@ -144,40 +160,67 @@ impl From<char> for Card {
             'Q' => Self::Queen,
             'K' => Self::King,
             'A' => Self::Ace,
-            _ => panic!("invalid char for card {c}"),
-        }
+            _ => return Err(ParseError::at_char(1, c, "card (2-9, T, J, Q, K or A)")),
+        })
     }
 }
 
-fn main() -> Result<(), Error> {
-    let input = File::open("input.txt")?;
-    let reader = BufReader::new(input);
-
-    let mut hands: Vec<Hand> = reader
-        .lines()
-        .map(|line| line.unwrap().parse().unwrap())
+fn total_winnings(hands: &[Hand], jokers: bool) -> usize {
+    let mut hands: Vec<(WinType, Vec<usize>, usize)> = hands
+        .iter()
+        .map(|hand| {
+            (
+                get_win_type(&hand.cards, jokers),
+                hand.cards
+                    .iter()
+                    .map(|card| card.strength(jokers))
+                    .collect(),
+                hand.bid,
+            )
+        })
         .collect();
 
     hands.sort_by(|a, b| {
-        if a.win_type == b.win_type {
-            for (a_card, b_card) in a.cards.iter().zip(b.cards.iter()) {
-                match a_card.partial_cmp(b_card).unwrap() {
+        if a.0 == b.0 {
+            for (a_card, b_card) in a.1.iter().zip(b.1.iter()) {
+                match a_card.cmp(b_card) {
                     Ordering::Equal => continue,
                     ord => return ord,
                 }
             }
         }
-        a.win_type.partial_cmp(&b.win_type).unwrap()
+        a.0.cmp(&b.0)
     });
 
-    let score: usize = hands
+    hands
         .iter()
         .enumerate()
-        .map(|(idx, hand)| hand.bid * (idx + 1))
-        .sum();
+        .map(|(idx, (_, _, bid))| bid * (idx + 1))
+        .sum()
+}
 
-    println!("Score: {score}");
+impl Solution for CamelPoker {
+    type Input = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                Ok(line?
+                    .parse()
+                    .map_err(|err: ParseError| err.at_line(line_idx + 1))?)
+            })
+            .collect()
+    }
 
-    Ok(())
-}
+    fn part1(hands: &Self::Input) -> Self::Part1 {
+        total_winnings(hands, false)
+    }
 
+    fn part2(hands: &Self::Input) -> Self::Part2 {
+        total_winnings(hands, true)
+    }
+}
@@ -0,0 +1,211 @@
+use std::{collections::HashMap, io::BufRead};
+
+use common::{split_once, Error, ParseError, Part, Solution};
+use num::integer::lcm;
+
+pub struct HauntedWasteland;
+
+pub struct Network {
+    turn_sequence: String,
+    map: HashMap<String, (String, String)>,
+    // lines read, so missing nodes are reported past the last one
+    line_count: usize,
+}
+
+impl Network {
+    fn count_steps(&self, start: &str, is_end: impl Fn(&str) -> bool) -> u64 {
+        let mut steps: u64 = 0;
+        let mut current_location = start;
+
+        for turn in self.turn_sequence.chars().cycle() {
+            if is_end(current_location) {
+                break;
+            }
+
+            let (left, right) = &self.map[current_location];
+
+            current_location = match turn {
+                'L' => left,
+                'R' => right,
+                _ => panic!("unexpected turn {turn}"),
+            };
+
+            steps += 1;
+        }
+
+        steps
+    }
+}
+
+fn parse_node(line: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let (location, turns) = split_once(line, " = ")?;
+    let turns = turns
+        .strip_prefix('(')
+        .and_then(|turns| turns.strip_suffix(')'))
+        .ok_or_else(|| ParseError::at(line, turns, "`(left, right)`"))?;
+
+    let (left, right) =
+        split_once(turns, ", ").map_err(|_| ParseError::at(line, turns, "`left, right`"))?;
+
+    Ok((location, (left, right)))
+}
+
+// fails unless some node satisfies `is_wanted`
+fn require_node(
+    network: &Network,
+    is_wanted: impl Fn(&str) -> bool,
+    expected: &str,
+) -> Result<(), ParseError> {
+    if network.map.keys().any(|location| is_wanted(location)) {
+        return Ok(());
+    }
+
+    Err(ParseError::end_of("", expected).at_line(network.line_count + 1))
+}
+
+impl Solution for HauntedWasteland {
+    type Input = Network;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        let mut lines = reader.lines().enumerate();
+
+        let turn_sequence = match lines.next() {
+            Some((_, line)) => line?,
+            None => String::new(),
+        };
+
+        if turn_sequence.is_empty() {
+            return Err(ParseError::end_of(&turn_sequence, "turn sequence")
+                .at_line(1)
+                .into());
+        }
+
+        if let Some((idx, turn)) = turn_sequence
+            .chars()
+            .enumerate()
+            .find(|(_, turn)| !matches!(turn, 'L' | 'R'))
+        {
+            return Err(ParseError::at_char(idx + 1, turn, "`L` or `R`")
+                .at_line(1)
+                .into());
+        }
+
+        // skip empty line
+        if let Some((_, line)) = lines.next() {
+            let line = line?;
+            if !line.is_empty() {
+                return Err(ParseError::at(&line, &line, "empty line").at_line(2).into());
+            }
+        }
+
+        let mut map: HashMap<String, (String, String)> = HashMap::new();
+        let mut node_lines = vec![];
+
+        for (line_idx, line) in lines {
+            let line = line?;
+            let (location, (left, right)) =
+                parse_node(&line).map_err(|err| err.at_line(line_idx + 1))?;
+
+            map.insert(location.to_owned(), (left.to_owned(), right.to_owned()));
+            node_lines.push((line_idx + 1, line));
+        }
+
+        // every turn has to lead to a node of the map
+        for (line_no, line) in &node_lines {
+            let (_, (left, right)) = parse_node(line).expect("line was parsed before");
+            if let Some(unknown) = [left, right]
+                .into_iter()
+                .find(|node| !map.contains_key(*node))
+            {
+                return Err(ParseError::at(line, unknown, "known node")
+                    .at_line(*line_no)
+                    .into());
+            }
+        }
+
+        let line_count = node_lines.last().map_or(2, |(line_no, _)| *line_no);
+        Ok(Network {
+            turn_sequence,
+            map,
+            line_count,
+        })
+    }
+
+    fn check(network: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::One => {
+                require_node(network, |location| location == "AAA", "node `AAA`")?;
+                require_node(network, |location| location == "ZZZ", "node `ZZZ`")
+            }
+            Part::Two => {
+                require_node(
+                    network,
+                    |location| location.ends_with('A'),
+                    "node ending in `A`",
+                )?;
+                require_node(
+                    network,
+                    |location| location.ends_with('Z'),
+                    "node ending in `Z`",
+                )
+            }
+        }
+    }
+
+    fn part1(network: &Self::Input) -> Self::Part1 {
+        network.count_steps("AAA", |location| location == "ZZZ")
+    }
+
+    fn part2(network: &Self::Input) -> Self::Part2 {
+        network
+            .map
+            .keys()
+            .filter(|location| location.ends_with('A'))
+            .map(|location| network.count_steps(location, |location| location.ends_with('Z')))
+            .fold(1, lcm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GHOSTS: &str =
+        "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\nXXX = (XXX, XXX)";
+
+    #[test]
+    fn rejects_unknown_nodes() {
+        let err = HauntedWasteland::parse_str("L\n\nAAA = (BBB, AAA)\nZZZ = (ZZZ, ZZZ)")
+            .err()
+            .unwrap();
+
+        assert_eq!((err.line(), err.column()), (Some(3), 8));
+        assert_eq!(err.expected(), "known node");
+        assert_eq!(err.found(), Some("BBB"));
+    }
+
+    #[test]
+    fn part_one_needs_aaa_and_zzz() {
+        let network = HauntedWasteland::parse_str(GHOSTS).unwrap();
+
+        let err = HauntedWasteland::check(&network, Part::One).unwrap_err();
+        assert_eq!((err.line(), err.expected()), (Some(7), "node `AAA`"));
+        assert_eq!(HauntedWasteland::check(&network, Part::Two), Ok(()));
+
+        let network = HauntedWasteland::parse_str("L\n\nAAA = (AAA, AAA)").unwrap();
+        let err = HauntedWasteland::check(&network, Part::One).unwrap_err();
+        assert_eq!(err.expected(), "node `ZZZ`");
+    }
+
+    #[test]
+    fn solve_reports_failed_checks() {
+        let err = common::solve::<HauntedWasteland>(GHOSTS.as_bytes(), &[Part::Two, Part::One])
+            .unwrap_err();
+        assert!(matches!(err, Error::Parse(err) if err.expected() == "node `AAA`"));
+
+        let answers = common::solve::<HauntedWasteland>(GHOSTS.as_bytes(), &[Part::Two]).unwrap();
+        assert_eq!(answers, ["2"]);
+    }
+}
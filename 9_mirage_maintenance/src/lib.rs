@@ -0,0 +1,99 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use common::{parse_token, Error, ParseError, Solution};
+
+pub struct MirageMaintenance;
+
+fn build_chart(numbers: &[isize]) -> Vec<VecDeque<isize>> {
+    let mut chart = vec![numbers.iter().copied().collect::<VecDeque<isize>>()];
+    while chart
+        .last()
+        .expect("always one chart")
+        .iter()
+        .any(|e| *e != 0)
+    {
+        let numbers = chart.last().unwrap();
+
+        chart.push(
+            numbers
+                .iter()
+                .zip(numbers.iter().skip(1))
+                .map(|(prev, next)| next - prev)
+                .collect(),
+        );
+    }
+
+    chart
+}
+
+fn extrapolate_forwards(numbers: &[isize]) -> isize {
+    let mut chart = build_chart(numbers);
+
+    for i in (0..chart.len()).rev() {
+        if i == chart.len() - 1 {
+            chart[i].push_back(0);
+            continue;
+        }
+
+        let next = chart[i].back().expect("always one item")
+            + chart[i + 1].back().expect("always one item");
+
+        chart[i].push_back(next);
+    }
+
+    *chart.first().unwrap().back().unwrap()
+}
+
+fn extrapolate_backwards(numbers: &[isize]) -> isize {
+    let mut chart = build_chart(numbers);
+
+    for i in (0..chart.len()).rev() {
+        if i == chart.len() - 1 {
+            chart[i].push_front(0);
+            continue;
+        }
+
+        let next = chart[i].front().expect("always one item")
+            - chart[i + 1].front().expect("always one item");
+
+        chart[i].push_front(next);
+    }
+
+    *chart.first().unwrap().front().unwrap()
+}
+
+impl Solution for MirageMaintenance {
+    type Input = Vec<Vec<isize>>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                let line = line?;
+
+                Ok(line
+                    .split_whitespace()
+                    .map(|item| parse_token::<isize>(&line, item, "number"))
+                    .collect::<Result<_, ParseError>>()
+                    .map_err(|err| err.at_line(line_idx + 1))?)
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Input) -> Self::Part1 {
+        histories
+            .iter()
+            .map(|numbers| extrapolate_forwards(numbers))
+            .sum()
+    }
+
+    fn part2(histories: &Self::Input) -> Self::Part2 {
+        histories
+            .iter()
+            .map(|numbers| extrapolate_backwards(numbers))
+            .sum()
+    }
+}
@@ -0,0 +1,52 @@
+//! Benchmarks every day against its `test.txt`. Run with `cargo bench -p aoc`;
+//! criterion collects all days into `target/criterion/report/index.html`.
+
+use std::hint::black_box;
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// times each phase on its own, so a slow part two does not hide behind parsing
+fn bench_day<S: Solution>(c: &mut Criterion, name: &str, input: &str) {
+    let mut group = c.benchmark_group(name);
+    let parsed = S::parse_str(input).expect("benchmark input parses");
+
+    group.bench_function("parse", |b| b.iter(|| S::parse_str(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($solution:ty => $dir:literal),* $(,)?) => {
+        fn bench_all_days(c: &mut Criterion) {
+            $(bench_day::<$solution>(c, $dir, include_str!(concat!("../../", $dir, "/test.txt")));)*
+        }
+    };
+}
+
+bench_days! {
+    trebuchet::Trebuchet => "1_trebuchet",
+    cube_conundrum::CubeConundrum => "2_cube_conundrum",
+    gear_ratios::GearRatios => "3_gear_ratios",
+    scratchcards::Scratchcards => "4_scratchcards",
+    fertilizer::Fertilizer => "5_fertilizer",
+    boat_race::BoatRace => "6_boat_race",
+    camel_poker::CamelPoker => "7_camel_poker",
+    haunted_wasteland::HauntedWasteland => "8_haunted_wasteland",
+    mirage_maintenance::MirageMaintenance => "9_mirage_maintenance",
+    pipe_maze::PipeMaze => "10_pipe_maze",
+    cosmic_expansion::CosmicExpansion => "11_cosmic_expansion",
+    hot_springs::HotSprings => "12_hot_springs",
+    mirrors::Mirrors => "13_mirrors",
+    rocks::Rocks => "14_rocks",
+    lens_library::LensLibrary => "15_lens_library",
+    more_mirrors::MoreMirrors => "16_more_mirrors",
+    clumsy_crucible::ClumsyCrucible => "17_clumsy_crucible",
+    lavaduct_lagoon::LavaductLagoon => "18_lavaduct_lagoon",
+    aplenty::Aplenty => "19_aplenty",
+}
+
+criterion_group!(benches, bench_all_days);
+criterion_main!(benches);
@@ -0,0 +1,211 @@
+use std::{fmt::Display, io, time::Duration};
+
+use common::Part;
+
+use crate::client::{HttpClient, Response};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+
+#[derive(Debug)]
+pub enum ApiError {
+    Io(io::Error),
+    MissingSession,
+    Status(u16, String),
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::Io(err) => write!(f, "{err}"),
+            ApiError::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or store it in the `session` file of the cache directory"
+            ),
+            ApiError::Status(status, body) => {
+                write!(f, "server responded with {status}: {}", body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ApiError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ApiError {
+    fn from(err: io::Error) -> Self {
+        ApiError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Duration,
+    },
+    /// The previous answer was too recent, nothing was judged.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part is already solved, or part two is still locked.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::Incorrect { hint, wait } => {
+                write!(f, "that's not the right answer")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it is too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it is too low")?,
+                    None => {}
+                }
+                write!(f, " (wait {}s before trying again)", wait.as_secs())
+            }
+            Verdict::TooSoon { wait } => {
+                write!(f, "answered too recently, {}s left to wait", wait.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "this part is already solved or still locked"),
+            Verdict::Unknown(text) => write!(f, "unexpected response: {text}"),
+        }
+    }
+}
+
+// the website only answers in prose, so the verdict is read off the message
+fn parse_verdict(body: &str) -> Verdict {
+    if body.contains("That's the right answer") {
+        return Verdict::Correct;
+    }
+
+    if body.contains("That's not the right answer") {
+        let hint = if body.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        // "please wait one minute before trying again"
+        let wait = text_between(body, "wait ", " before trying again")
+            .and_then(parse_duration)
+            .unwrap_or(Duration::from_secs(60));
+
+        return Verdict::Incorrect { hint, wait };
+    }
+
+    if body.contains("You gave an answer too recently") {
+        // "You have 1m 23s left to wait"
+        let wait = text_between(body, "You have ", " left to wait")
+            .and_then(parse_duration)
+            .unwrap_or(Duration::from_secs(60));
+
+        return Verdict::TooSoon { wait };
+    }
+
+    if body.contains("You don't seem to be solving the right level") {
+        return Verdict::WrongLevel;
+    }
+
+    Verdict::Unknown(body.trim().to_string())
+}
+
+fn text_between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    let (between, _) = rest.split_once(end)?;
+
+    Some(between)
+}
+
+// understands both "one minute" / "5 minutes" and "1h 2m 3s"
+fn parse_duration(text: &str) -> Option<Duration> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    if let [amount, unit] = words.as_slice() {
+        let amount = match *amount {
+            "one" | "a" | "an" => Some(1),
+            amount => amount.parse().ok(),
+        };
+
+        let unit = match unit.trim_end_matches('s') {
+            "second" => Some(1),
+            "minute" => Some(60),
+            "hour" => Some(3600),
+            _ => None,
+        };
+
+        if let (Some(amount), Some(unit)) = (amount, unit) {
+            return Some(Duration::from_secs(amount * unit));
+        }
+    }
+
+    words.iter().try_fold(Duration::ZERO, |total, word| {
+        let (amount, unit) = word.split_at(word.len().checked_sub(1)?);
+        let amount: u64 = amount.parse().ok()?;
+
+        let unit = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 3600,
+            _ => return None,
+        };
+
+        Some(total + Duration::from_secs(amount * unit))
+    })
+}
+
+/// The puzzle website, reached through `client` and authenticated with the
+/// user's session cookie.
+pub struct Api<C> {
+    client: C,
+    base_url: String,
+    session: String,
+}
+
+impl<C: HttpClient> Api<C> {
+    pub fn new(client: C, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, ApiError> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+
+        match self.client.get(&url, &self.session)? {
+            Response { status: 200, body } => Ok(body),
+            Response { status, body } => Err(ApiError::Status(status, body)),
+        }
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ApiError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+
+        match self.client.post_form(&url, &self.session, &form)? {
+            Response { status: 200, body } => Ok(parse_verdict(&body)),
+            Response { status, body } => Err(ApiError::Status(status, body)),
+        }
+    }
+}
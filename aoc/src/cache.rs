@@ -0,0 +1,90 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::Part;
+
+use crate::{
+    api::{Api, ApiError, YEAR},
+    client::HttpClient,
+    history::History,
+};
+
+/// Per-user directory holding downloaded puzzle inputs and the session token.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `$AOC_CACHE_DIR`, falling back to the platform's cache directory.
+    pub fn default_dir() -> Option<PathBuf> {
+        if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+            return Some(dir.into());
+        }
+
+        let base = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+
+        Some(base.join("aoc"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn history_path(&self, day: u8, part: Part) -> PathBuf {
+        self.dir
+            .join(YEAR.to_string())
+            .join(format!("day{day}-part{part}.answers"))
+    }
+
+    pub fn history(&self, day: u8, part: Part) -> io::Result<History> {
+        History::load(self.history_path(day, part), day, part)
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(YEAR.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    /// `$AOC_SESSION`, or the contents of the `session` file in the cache.
+    pub fn session(&self) -> Option<String> {
+        env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(self.dir.join("session")).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+    }
+
+    /// Returns the path of the cached input for `day`, downloading it first
+    /// if it is not cached yet.
+    pub fn fetch_input<C: HttpClient>(&self, api: &Api<C>, day: u8) -> Result<PathBuf, ApiError> {
+        let path = self.input_path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = api.input(day)?;
+        write_atomically(&path, &input)?;
+
+        Ok(path)
+    }
+}
+
+// a cancelled download must not leave a truncated input that looks cached
+pub(crate) fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let dir = path.parent().expect("cache files live in a directory");
+    fs::create_dir_all(dir)?;
+
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(tmp, path)
+}
@@ -0,0 +1,70 @@
+use std::io;
+
+const USER_AGENT: &str = "aoc-2023 runner (github.com/CakeWithDivinity/aoc-2023)";
+
+/// A response as the puzzle website sent it, whatever its status.
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP transport used to talk to the puzzle website, so the commands can
+/// be pointed at a local server in tests.
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> io::Result<Response>;
+
+    /// Posts `form` url-encoded, as the website's answer form does.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> io::Result<Response>;
+}
+
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> io::Result<Response> {
+        into_response(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={session}"))
+                .call(),
+        )
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> io::Result<Response> {
+        into_response(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={session}"))
+                .send_form(form),
+        )
+    }
+}
+
+// ureq reports non-2xx statuses as errors, but the caller wants to see them
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> io::Result<Response> {
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(err)) => return Err(io::Error::other(err)),
+    };
+
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
@@ -0,0 +1,263 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use common::Part;
+
+use crate::{
+    api::{Api, ApiError, Hint, Verdict},
+    cache::write_atomically,
+    client::HttpClient,
+};
+
+/// Why an answer was not sent to the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong {
+        hint: Option<Hint>,
+    },
+    /// At least as high as an answer that was already too high.
+    AboveTooHigh {
+        known: String,
+    },
+    /// At most as low as an answer that was already too low.
+    BelowTooLow {
+        known: String,
+    },
+    RateLimited {
+        wait: Duration,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong { hint: None } => write!(f, "this answer was already rejected"),
+            Refusal::KnownWrong {
+                hint: Some(Hint::TooHigh),
+            } => write!(f, "this answer was already rejected as too high"),
+            Refusal::KnownWrong {
+                hint: Some(Hint::TooLow),
+            } => write!(f, "this answer was already rejected as too low"),
+            Refusal::AboveTooHigh { known } => write!(f, "{known} was already too high"),
+            Refusal::BelowTooLow { known } => write!(f, "{known} was already too low"),
+            Refusal::RateLimited { wait } => {
+                write!(f, "wait {}s before submitting again", wait.as_secs())
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Api(ApiError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted, {refusal}"),
+            SubmitError::Api(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ApiError> for SubmitError {
+    fn from(err: ApiError) -> Self {
+        SubmitError::Api(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Api(ApiError::Io(err))
+    }
+}
+
+/// Every answer submitted for one part of a day, kept next to the cached
+/// input so wrong guesses are never sent twice.
+///
+/// The file holds one entry per line: `correct <answer>`,
+/// `wrong <answer> [too-high|too-low]` or `wait-until <unix seconds>`.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    day: u8,
+    part: Part,
+    correct: Option<String>,
+    wrong: Vec<(String, Option<Hint>)>,
+    locked_until: Option<SystemTime>,
+}
+
+impl History {
+    /// Loads the history stored at `path`, which may not exist yet.
+    pub fn load(path: impl Into<PathBuf>, day: u8, part: Part) -> io::Result<Self> {
+        let mut history = Self {
+            path: path.into(),
+            day,
+            part,
+            correct: None,
+            wrong: Vec::new(),
+            locked_until: None,
+        };
+
+        let contents = match fs::read_to_string(&history.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(history),
+            Err(err) => return Err(err),
+        };
+
+        for line in contents.lines() {
+            let mut tokens = line.split_whitespace();
+
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some("correct"), Some(answer), None) => history.correct = Some(answer.to_string()),
+                (Some("wrong"), Some(answer), hint) => {
+                    let hint = match hint {
+                        Some("too-high") => Some(Hint::TooHigh),
+                        Some("too-low") => Some(Hint::TooLow),
+                        _ => None,
+                    };
+
+                    history.wrong.push((answer.to_string(), hint));
+                }
+                (Some("wait-until"), Some(secs), None) => {
+                    history.locked_until = secs
+                        .parse()
+                        .ok()
+                        .map(|secs| UNIX_EPOCH + Duration::from_secs(secs));
+                }
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: unexpected line {line:?}", history.path.display()),
+                    ))
+                }
+            }
+        }
+
+        Ok(history)
+    }
+
+    pub fn correct(&self) -> Option<&str> {
+        self.correct.as_deref()
+    }
+
+    pub fn wrong(&self) -> &[(String, Option<Hint>)] {
+        &self.wrong
+    }
+
+    /// Checks `answer` against everything already known about this part.
+    pub fn check(&self, answer: &str, now: SystemTime) -> Result<(), Refusal> {
+        if let Some(correct) = &self.correct {
+            return Err(Refusal::AlreadySolved {
+                answer: correct.clone(),
+            });
+        }
+
+        if let Some((_, hint)) = self.wrong.iter().find(|(wrong, _)| wrong == answer) {
+            return Err(Refusal::KnownWrong { hint: *hint });
+        }
+
+        // hints only bound numeric answers
+        if let Ok(value) = answer.parse::<i128>() {
+            for (wrong, hint) in &self.wrong {
+                let Ok(known) = wrong.parse::<i128>() else {
+                    continue;
+                };
+
+                match hint {
+                    Some(Hint::TooHigh) if value >= known => {
+                        return Err(Refusal::AboveTooHigh {
+                            known: wrong.clone(),
+                        })
+                    }
+                    Some(Hint::TooLow) if value <= known => {
+                        return Err(Refusal::BelowTooLow {
+                            known: wrong.clone(),
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(wait) = self
+            .locked_until
+            .and_then(|until| until.duration_since(now).ok())
+            .filter(|wait| !wait.is_zero())
+        {
+            return Err(Refusal::RateLimited { wait });
+        }
+
+        Ok(())
+    }
+
+    /// Remembers how the website judged `answer` and saves the history.
+    pub fn record(&mut self, answer: &str, verdict: &Verdict, now: SystemTime) -> io::Result<()> {
+        match verdict {
+            Verdict::Correct => self.correct = Some(answer.to_string()),
+            Verdict::Incorrect { hint, wait } => {
+                self.wrong.push((answer.to_string(), *hint));
+                self.locked_until = Some(now + *wait);
+            }
+            Verdict::TooSoon { wait } => self.locked_until = Some(now + *wait),
+            Verdict::WrongLevel | Verdict::Unknown(_) => return Ok(()),
+        }
+
+        self.save()
+    }
+
+    /// Sends `answer` unless the history already rules it out, and records
+    /// the verdict.
+    pub fn submit<C: HttpClient>(
+        &mut self,
+        api: &Api<C>,
+        answer: &str,
+        now: SystemTime,
+    ) -> Result<Verdict, SubmitError> {
+        self.check(answer, now).map_err(SubmitError::Refused)?;
+
+        let verdict = api.submit(self.day, self.part, answer)?;
+        self.record(answer, &verdict, now)?;
+
+        Ok(verdict)
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let mut contents = String::new();
+
+        for (answer, hint) in &self.wrong {
+            let hint = match hint {
+                Some(Hint::TooHigh) => " too-high",
+                Some(Hint::TooLow) => " too-low",
+                None => "",
+            };
+
+            contents.push_str(&format!("wrong {answer}{hint}\n"));
+        }
+
+        if let Some(answer) = &self.correct {
+            contents.push_str(&format!("correct {answer}\n"));
+        }
+
+        if let Some(until) = self.locked_until {
+            // rounded up so the lock never ends early
+            let until = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+            let secs = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+            contents.push_str(&format!("wait-until {secs}\n"));
+        }
+
+        write_atomically(&self.path, &contents)
+    }
+}
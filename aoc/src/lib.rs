@@ -0,0 +1,78 @@
+use std::{io::BufRead, ops::RangeInclusive};
+
+use common::{solve, solve_input, Error, Part, Solution};
+use gear_ratios::{GearRatios, Rule, Schematic, SymbolClass};
+use trebuchet::{Calibration, Trebuchet, Vocabulary};
+
+pub mod api;
+pub mod cache;
+pub mod client;
+pub mod history;
+
+pub const DAYS: RangeInclusive<u8> = 1..=19;
+
+/// Settings for the days whose puzzle can be varied from the command line.
+/// The defaults solve the puzzles as published.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// The digit words of day 1, English if unset.
+    pub vocabulary: Option<Vocabulary>,
+    /// The characters day 3 counts as symbols, anything but digits and `.`
+    /// if unset.
+    pub symbols: Option<SymbolClass>,
+    /// The rule day 3 aggregates in part two instead of the gear ratio.
+    pub rule: Option<Rule>,
+}
+
+/// Parses the input of `day` and solves the requested parts.
+///
+/// Panics if `day` is not one of [`DAYS`].
+pub fn solve_day(
+    day: u8,
+    reader: impl BufRead,
+    parts: &[Part],
+    options: &Options,
+) -> Result<Vec<String>, Error> {
+    match day {
+        1 => match &options.vocabulary {
+            Some(vocabulary) => Ok(solve_input::<Trebuchet>(
+                &Calibration::read(reader, vocabulary)?,
+                parts,
+            )?),
+            None => solve::<Trebuchet>(reader, parts),
+        },
+        2 => solve::<cube_conundrum::CubeConundrum>(reader, parts),
+        3 => {
+            let symbols = options.symbols.clone().unwrap_or_default();
+            let schematic = Schematic::read(reader, &symbols)?;
+
+            match &options.rule {
+                Some(rule) => Ok(parts
+                    .iter()
+                    .map(|part| match part {
+                        Part::One => GearRatios::part1(&schematic).to_string(),
+                        Part::Two => schematic.aggregate(rule).to_string(),
+                    })
+                    .collect()),
+                None => Ok(solve_input::<GearRatios>(&schematic, parts)?),
+            }
+        }
+        4 => solve::<scratchcards::Scratchcards>(reader, parts),
+        5 => solve::<fertilizer::Fertilizer>(reader, parts),
+        6 => solve::<boat_race::BoatRace>(reader, parts),
+        7 => solve::<camel_poker::CamelPoker>(reader, parts),
+        8 => solve::<haunted_wasteland::HauntedWasteland>(reader, parts),
+        9 => solve::<mirage_maintenance::MirageMaintenance>(reader, parts),
+        10 => solve::<pipe_maze::PipeMaze>(reader, parts),
+        11 => solve::<cosmic_expansion::CosmicExpansion>(reader, parts),
+        12 => solve::<hot_springs::HotSprings>(reader, parts),
+        13 => solve::<mirrors::Mirrors>(reader, parts),
+        14 => solve::<rocks::Rocks>(reader, parts),
+        15 => solve::<lens_library::LensLibrary>(reader, parts),
+        16 => solve::<more_mirrors::MoreMirrors>(reader, parts),
+        17 => solve::<clumsy_crucible::ClumsyCrucible>(reader, parts),
+        18 => solve::<lavaduct_lagoon::LavaductLagoon>(reader, parts),
+        19 => solve::<aplenty::Aplenty>(reader, parts),
+        _ => panic!("day {day} is not solved yet"),
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+    time::SystemTime,
+};
+
+use aoc::{
+    api::{Api, ApiError, BASE_URL},
+    cache::Cache,
+    client::UreqClient,
+    solve_day, Options, DAYS,
+};
+use common::{Error, ParseError, Part};
+use trebuchet::{Mode, Scanner, Vocabulary, BUILTINS};
+
+const USAGE: &str = "usage: aoc run --day <1-19> [--part <1|2>] [--input <path|->]
+               [--vocabulary <en|de|fr|es|path>]
+               [--symbols <any|punctuation|chars>] [--rule <symbol|any>[/<parts>]:<sum|product|count>]
+       aoc fetch --day <1-19>
+       aoc submit --day <1-19> --part <1|2> [--input <path|->]
+       aoc explain --day 1 --part <1|2> [--input <path|->]
+               [--vocabulary <en|de|fr|es|path>]";
+
+enum Input {
+    // the downloaded input from the cache, fetching it if needed
+    Cached,
+    Stdin,
+    Path(PathBuf),
+}
+
+struct Args {
+    day: u8,
+    part: Option<Part>,
+    input: Input,
+    options: Options,
+}
+
+// a built-in language code, or the path of a vocabulary file
+fn load_vocabulary(value: &str) -> Result<Vocabulary, String> {
+    if BUILTINS.contains(&value) {
+        return Ok(Vocabulary::builtin(value).expect("listed as built-in"));
+    }
+
+    Vocabulary::load(value).map_err(|err| format!("{value}: {err}"))
+}
+
+// parses the value of `arg`, naming both in errors
+fn parse_option<T: FromStr<Err = ParseError>>(arg: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|err| format!("invalid {arg} {value}: {err}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = Input::Cached;
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {arg}"))?;
+
+        match arg.as_str() {
+            "--day" | "-d" => {
+                day = Some(
+                    value
+                        .parse::<u8>()
+                        .map_err(|_| format!("invalid day {value}"))?,
+                )
+            }
+            "--part" | "-p" => part = Some(value.parse::<Part>()?),
+            "--input" | "-i" if value == "-" => input = Input::Stdin,
+            "--input" | "-i" => input = Input::Path(PathBuf::from(value)),
+            "--vocabulary" => options.vocabulary = Some(load_vocabulary(&value)?),
+            "--symbols" => options.symbols = Some(parse_option(&arg, &value)?),
+            "--rule" => options.rule = Some(parse_option(&arg, &value)?),
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+
+    let day = day.ok_or("missing --day")?;
+    if !DAYS.contains(&day) {
+        return Err(format!("day {day} is not solved yet"));
+    }
+
+    if options.vocabulary.is_some() && day != 1 {
+        return Err("--vocabulary only applies to day 1".to_string());
+    }
+
+    if (options.symbols.is_some() || options.rule.is_some()) && day != 3 {
+        return Err("--symbols and --rule only apply to day 3".to_string());
+    }
+
+    Ok(Args {
+        day,
+        part,
+        input,
+        options,
+    })
+}
+
+fn open_cache() -> Result<Cache, String> {
+    Cache::default_dir()
+        .map(Cache::new)
+        .ok_or_else(|| "cannot find a cache directory, set AOC_CACHE_DIR".to_string())
+}
+
+fn open_api(cache: &Cache) -> Result<Api<UreqClient>, ApiError> {
+    let session = cache.session().ok_or(ApiError::MissingSession)?;
+    let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+
+    Ok(Api::new(UreqClient::new(), base_url, session))
+}
+
+fn fetch_input(day: u8) -> Result<PathBuf, String> {
+    let cache = open_cache()?;
+
+    // a cached input does not need a session token
+    let path = cache.input_path(day);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    open_api(&cache)
+        .and_then(|api| cache.fetch_input(&api, day))
+        .map_err(|err| format!("fetching day {day}: {err}"))
+}
+
+// hands the input selected by `args` to `read`, naming it in errors
+fn with_input<T>(
+    args: &Args,
+    read: impl FnOnce(&mut dyn BufRead) -> Result<T, Error>,
+) -> Result<T, String> {
+    let path = match &args.input {
+        Input::Cached => Some(fetch_input(args.day)?),
+        Input::Path(path) => Some(path.clone()),
+        Input::Stdin => None,
+    };
+
+    match path {
+        Some(path) => File::open(&path)
+            .map_err(Error::from)
+            .and_then(|file| read(&mut BufReader::new(file)))
+            .map_err(|err| format!("{}: {err}", path.display())),
+        None => read(&mut io::stdin().lock()).map_err(|err| format!("stdin: {err}")),
+    }
+}
+
+fn solve_args(args: &Args, parts: &[Part]) -> Result<Vec<String>, String> {
+    with_input(args, |reader| {
+        solve_day(args.day, reader, parts, &args.options)
+    })
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let answers = solve_args(&args, &parts)?;
+
+    if let [answer] = answers.as_slice() {
+        println!("{answer}");
+    } else {
+        for (part, answer) in parts.iter().zip(answers) {
+            println!("Part {part}: {answer}");
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(args: Args) -> Result<(), String> {
+    let part = args.part.expect("submit requires --part");
+    let answer = solve_args(&args, &[part])?.remove(0);
+    println!("{answer}");
+
+    let cache = open_cache()?;
+    let mut history = cache
+        .history(args.day, part)
+        .map_err(|err| format!("reading answer history: {err}"))?;
+
+    // refuse known-bad answers before asking for a session token
+    history
+        .check(&answer, SystemTime::now())
+        .map_err(|refusal| format!("not submitted, {refusal}"))?;
+
+    let api = open_api(&cache).map_err(|err| err.to_string())?;
+    let verdict = history
+        .submit(&api, &answer, SystemTime::now())
+        .map_err(|err| format!("submitting day {} part {part}: {err}", args.day))?;
+
+    println!("{verdict}");
+
+    Ok(())
+}
+
+fn explain(args: Args) -> Result<(), String> {
+    let mode = match args.part {
+        Some(Part::One) => Mode::Digits,
+        _ => Mode::DigitsAndWords,
+    };
+
+    let lines = with_input(&args, |reader| {
+        reader
+            .lines()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::from)
+    })?;
+    let vocabulary = args
+        .options
+        .vocabulary
+        .clone()
+        .unwrap_or_else(Vocabulary::english);
+    let scanner = Scanner::new(mode, &vocabulary);
+
+    let mut sum = 0;
+    let mut without_digit = 0;
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let explanation = scanner.explain(line.as_bytes());
+        println!("{}: {line}\n    {explanation}", line_idx + 1);
+
+        match explanation.value() {
+            Some(value) => sum += u64::from(value),
+            None => without_digit += 1,
+        }
+    }
+
+    println!("sum: {sum}");
+    if without_digit > 0 {
+        println!("skipped {without_digit} without a digit");
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+
+    let args = match command.as_deref() {
+        Some("run") => parse_args(args),
+        Some("fetch") => parse_args(args).and_then(|args| match args {
+            Args {
+                part: None,
+                input: Input::Cached,
+                ref options,
+                ..
+            } if *options == Options::default() => Ok(args),
+            _ => Err("fetch only takes --day".to_string()),
+        }),
+        Some("submit") => parse_args(args).and_then(|args| match args.part {
+            Some(_) => Ok(args),
+            None => Err("missing --part".to_string()),
+        }),
+        Some("explain") => parse_args(args).and_then(|args| match args {
+            Args {
+                day: 1,
+                part: Some(_),
+                ..
+            } => Ok(args),
+            Args { day: 1, .. } => Err("missing --part".to_string()),
+            _ => Err("explain only supports day 1".to_string()),
+        }),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let args = match args {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command.as_deref() {
+        Some("fetch") => fetch_input(args.day).map(|path| println!("{}", path.display())),
+        Some("submit") => submit(args),
+        Some("explain") => explain(args),
+        _ => run(args),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,90 @@
+use std::{
+    collections::HashSet,
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use aoc::{solve_day, Options, DAYS};
+use common::Part;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Manifest {
+    answer: Vec<Answer>,
+}
+
+#[derive(Deserialize)]
+struct Answer {
+    day: u8,
+    part: u8,
+    input: PathBuf,
+    expected: String,
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc lives inside the workspace")
+}
+
+fn load_manifest() -> Manifest {
+    let path = workspace_root().join("answers.toml");
+    let manifest = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", path.display()));
+
+    toml::from_str(&manifest).unwrap_or_else(|err| panic!("invalid {}: {err}", path.display()))
+}
+
+#[test]
+fn every_answer_matches() {
+    let mut failures = vec![];
+
+    for answer in load_manifest().answer {
+        let part: Part = answer.part.to_string().parse().expect("part is 1 or 2");
+        let path = workspace_root().join(&answer.input);
+        let label = format!(
+            "day {} part {part} ({})",
+            answer.day,
+            answer.input.display()
+        );
+
+        let result = File::open(&path).map_err(Into::into).and_then(|file| {
+            solve_day(
+                answer.day,
+                BufReader::new(file),
+                &[part],
+                &Options::default(),
+            )
+        });
+
+        match result.as_deref() {
+            Ok([actual]) if *actual == answer.expected => {}
+            Ok(actual) => failures.push(format!(
+                "{label}: expected {}, got {}",
+                answer.expected,
+                actual.join(", ")
+            )),
+            Err(err) => failures.push(format!("{label}: {err}")),
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn every_day_has_answers_for_both_parts() {
+    let covered: HashSet<(u8, u8)> = load_manifest()
+        .answer
+        .iter()
+        .map(|answer| (answer.day, answer.part))
+        .collect();
+
+    let missing: Vec<String> = DAYS
+        .flat_map(|day| [(day, 1), (day, 2)])
+        .filter(|key| !covered.contains(key))
+        .map(|(day, part)| format!("day {day} part {part}"))
+        .collect();
+
+    assert!(missing.is_empty(), "no answers for {}", missing.join(", "));
+}
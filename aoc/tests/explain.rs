@@ -0,0 +1,79 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+fn aoc(args: &[&str], input: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn explain_shows_the_tokens_behind_every_value() {
+    let (success, stdout) = aoc(
+        &["explain", "--day", "1", "--part", "2", "--input", "-"],
+        "eightwo\nxyz\n",
+    );
+
+    assert!(success);
+    assert_eq!(
+        stdout,
+        "1: eightwo\n    eight@0 two@4 => first 8, last 2, value 82\n\
+         2: xyz\n    no digit\n\
+         sum: 82\n\
+         skipped 1 without a digit\n"
+    );
+}
+
+#[test]
+fn lines_without_a_digit_add_nothing() {
+    let (success, stdout) = aoc(
+        &["run", "--day", "1", "--part", "1", "--input", "-"],
+        "a1b2\nnone\n",
+    );
+
+    assert!(success);
+    assert_eq!(stdout, "12\n");
+}
+
+#[test]
+fn explain_reads_the_chosen_vocabulary() {
+    let (success, stdout) = aoc(
+        &[
+            "explain",
+            "--day",
+            "1",
+            "--part",
+            "2",
+            "--input",
+            "-",
+            "--vocabulary",
+            "de",
+        ],
+        "zweiabc7\n",
+    );
+
+    assert!(success);
+    assert_eq!(
+        stdout,
+        "1: zweiabc7\n    zwei@0 7@7 => first 2, last 7, value 27\n\
+         sum: 27\n"
+    );
+}
@@ -0,0 +1,78 @@
+mod support;
+
+use std::{fs, process::Command};
+
+use aoc::{
+    api::{Api, ApiError},
+    cache::Cache,
+    client::UreqClient,
+};
+use support::{temp_dir, StubServer};
+
+#[test]
+fn fetch_downloads_the_input_once_and_caches_it() {
+    let server = StubServer::start(vec![(200, "1abc2\n")]);
+    let cache = Cache::new(temp_dir("fetch-caches"));
+    let api = Api::new(UreqClient::new(), server.url(), "secret");
+
+    let path = cache.fetch_input(&api, 1).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+
+    // the stub only answers once, so this has to be served from the cache
+    assert_eq!(cache.fetch_input(&api, 1).unwrap(), path);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn fetch_caches_nothing_when_the_server_refuses() {
+    let server = StubServer::start(vec![(400, "Puzzle inputs differ by user.  Please log in.")]);
+    let cache = Cache::new(temp_dir("fetch-refused"));
+    let api = Api::new(UreqClient::new(), server.url(), "expired");
+
+    let err = cache.fetch_input(&api, 2).unwrap_err();
+    assert!(matches!(err, ApiError::Status(400, _)), "{err}");
+    assert!(!cache.input_path(2).exists());
+
+    server.requests();
+}
+
+#[test]
+fn run_fetches_a_missing_input_before_solving() {
+    let server = StubServer::start(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+    let cache_dir = temp_dir("run-fetches");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--part", "1"])
+        .env("AOC_CACHE_DIR", &cache_dir)
+        .env("AOC_BASE_URL", server.url())
+        .env("AOC_SESSION", "secret")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "50\n");
+    assert!(Cache::new(&cache_dir).input_path(1).exists());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn run_uses_a_cached_input_without_a_session() {
+    let cache = Cache::new(temp_dir("run-cached"));
+    fs::create_dir_all(cache.input_path(6).parent().unwrap()).unwrap();
+    fs::write(cache.input_path(6), "Time:      7\nDistance:  9\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "6", "--part", "1"])
+        .env("AOC_CACHE_DIR", cache.dir())
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "4\n");
+}
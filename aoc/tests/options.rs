@@ -0,0 +1,48 @@
+use aoc::{solve_day, Options};
+use common::Part;
+use trebuchet::Vocabulary;
+
+const GERMAN: &str = "zweiabc7\nxneunx3\none1\n";
+
+#[test]
+fn day_1_reads_the_chosen_vocabulary() {
+    let options = Options {
+        vocabulary: Some(Vocabulary::german()),
+        ..Options::default()
+    };
+
+    let answers = solve_day(1, GERMAN.as_bytes(), &[Part::One, Part::Two], &options).unwrap();
+    assert_eq!(answers, ["121", "131"]);
+}
+
+#[test]
+fn day_1_defaults_to_english() {
+    let answers = solve_day(1, GERMAN.as_bytes(), &[Part::Two], &Options::default()).unwrap();
+    assert_eq!(answers, ["121"]);
+}
+
+const SCHEMATIC: &str = "12.3\n.*.#\n4+..\n";
+
+#[test]
+fn day_3_reads_the_chosen_symbols_and_rule() {
+    let options = Options {
+        symbols: Some("*+".parse().unwrap()),
+        rule: Some("any:sum".parse().unwrap()),
+        ..Options::default()
+    };
+
+    let answers = solve_day(3, SCHEMATIC.as_bytes(), &[Part::One, Part::Two], &options).unwrap();
+    assert_eq!(answers, ["16", "20"]);
+}
+
+#[test]
+fn day_3_defaults_to_gear_ratios() {
+    let answers = solve_day(
+        3,
+        SCHEMATIC.as_bytes(),
+        &[Part::One, Part::Two],
+        &Options::default(),
+    )
+    .unwrap();
+    assert_eq!(answers, ["19", "48"]);
+}
@@ -0,0 +1,140 @@
+mod support;
+
+use std::{
+    fs,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{
+    api::{Api, Hint, Verdict},
+    cache::Cache,
+    client::UreqClient,
+    history::{Refusal, SubmitError},
+};
+use common::Part;
+use support::{temp_dir, StubServer};
+
+const CORRECT: &str =
+    "<article><p>That's the right answer!  You are one gold star closer.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+    Please wait one minute before trying again.</p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after \
+    submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>";
+
+#[test]
+fn submit_posts_the_answer_and_remembers_a_correct_one() {
+    let server = StubServer::start(vec![(200, CORRECT)]);
+    let cache = Cache::new(temp_dir("submit-correct"));
+    let api = Api::new(UreqClient::new(), server.url(), "secret");
+    let now = SystemTime::now();
+
+    let mut history = cache.history(3, Part::Two).unwrap();
+    assert_eq!(history.submit(&api, "4361", now).unwrap(), Verdict::Correct);
+
+    // reloaded from disk, the solved part is never submitted again
+    let mut history = cache.history(3, Part::Two).unwrap();
+    assert_eq!(history.correct(), Some("4361"));
+    let err = history.submit(&api, "4361", now).unwrap_err();
+    assert!(
+        matches!(err, SubmitError::Refused(Refusal::AlreadySolved { .. })),
+        "{err}"
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/3/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=2&answer=4361");
+}
+
+#[test]
+fn submit_refuses_answers_ruled_out_by_a_too_high_hint() {
+    let server = StubServer::start(vec![(200, TOO_HIGH)]);
+    let cache = Cache::new(temp_dir("submit-too-high"));
+    let api = Api::new(UreqClient::new(), server.url(), "secret");
+    let now = SystemTime::now();
+
+    let mut history = cache.history(1, Part::One).unwrap();
+    assert_eq!(
+        history.submit(&api, "500", now).unwrap(),
+        Verdict::Incorrect {
+            hint: Some(Hint::TooHigh),
+            wait: Duration::from_secs(60),
+        }
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    let history = cache.history(1, Part::One).unwrap();
+    assert_eq!(history.wrong(), [("500".to_string(), Some(Hint::TooHigh))]);
+
+    let later = now + Duration::from_secs(120);
+    assert_eq!(
+        history.check("500", later),
+        Err(Refusal::KnownWrong {
+            hint: Some(Hint::TooHigh)
+        })
+    );
+    assert_eq!(
+        history.check("612", later),
+        Err(Refusal::AboveTooHigh {
+            known: "500".to_string()
+        })
+    );
+    assert!(matches!(
+        history.check("499", now),
+        Err(Refusal::RateLimited { .. })
+    ));
+    assert_eq!(history.check("499", later), Ok(()));
+}
+
+#[test]
+fn submit_waits_out_the_rate_limit() {
+    let server = StubServer::start(vec![(200, TOO_SOON)]);
+    let cache = Cache::new(temp_dir("submit-too-soon"));
+    let api = Api::new(UreqClient::new(), server.url(), "secret");
+    let now = UNIX_EPOCH + Duration::from_secs(1_701_406_800);
+
+    let mut history = cache.history(5, Part::One).unwrap();
+    assert_eq!(
+        history.submit(&api, "35", now).unwrap(),
+        Verdict::TooSoon {
+            wait: Duration::from_secs(83),
+        }
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    // nothing was judged, so the same answer may be sent once the wait is over
+    let history = cache.history(5, Part::One).unwrap();
+    assert!(history.wrong().is_empty());
+    assert_eq!(
+        history.check("35", now + Duration::from_secs(10)),
+        Err(Refusal::RateLimited {
+            wait: Duration::from_secs(73)
+        })
+    );
+    assert_eq!(history.check("35", now + Duration::from_secs(83)), Ok(()));
+}
+
+#[test]
+fn submit_command_refuses_a_known_wrong_answer_without_a_session() {
+    let cache = Cache::new(temp_dir("submit-command"));
+    fs::create_dir_all(cache.input_path(6).parent().unwrap()).unwrap();
+    fs::write(cache.input_path(6), "Time:      7\nDistance:  9\n").unwrap();
+    fs::write(cache.history_path(6, Part::One), "wrong 4 too-low\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "--day", "6", "--part", "1"])
+        .env("AOC_CACHE_DIR", cache.dir())
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "4\n");
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("already rejected as too low"),
+        "{output:?}"
+    );
+}
@@ -0,0 +1,150 @@
+use std::{fmt::Display, io, str::FromStr};
+
+/// A malformed puzzle input, pointing at the offending token.
+///
+/// Lines and columns are 1-based. Parsers that only see a single line leave
+/// the line unset and the caller attaches it with [`ParseError::at_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: Option<usize>,
+    column: usize,
+    expected: String,
+    // `None` when the input ended before the expected token
+    found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: impl Into<String>, found: Option<&str>) -> Self {
+        Self {
+            line: None,
+            column,
+            expected: expected.into(),
+            found: found.map(str::to_string),
+        }
+    }
+
+    /// Creates an error for `token`, which has to be a slice of `source`.
+    pub fn at(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(column_of(source, token), expected, Some(token))
+    }
+
+    /// Creates an error for a single unexpected character.
+    pub fn at_char(column: usize, found: char, expected: impl Into<String>) -> Self {
+        Self::new(column, expected, Some(found.encode_utf8(&mut [0; 4])))
+    }
+
+    /// Creates an error for a token that is missing at the end of `source`.
+    pub fn end_of(source: &str, expected: impl Into<String>) -> Self {
+        Self::new(source.chars().count() + 1, expected, None)
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Moves the error right by `columns`, for errors raised while parsing a
+    /// slice that does not start at the beginning of the line.
+    pub fn shifted(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> Option<&str> {
+        self.found.as_deref()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}, ")?;
+        }
+
+        write!(f, "column {}: expected {}", self.column, self.expected)?;
+
+        match &self.found {
+            Some(found) if found.is_empty() => write!(f, ", found nothing"),
+            Some(found) => write!(f, ", found `{found}`"),
+            None => write!(f, ", found end of line"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based column of `token` inside `source`.
+pub fn column_of(source: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+    debug_assert!(offset <= source.len(), "token is not a slice of source");
+
+    source
+        .get(..offset)
+        .map_or(1, |prefix| prefix.chars().count() + 1)
+}
+
+/// Parses `token`, a slice of `source`, reporting its position on failure.
+pub fn parse_token<T: FromStr>(
+    source: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, expected))
+}
+
+/// Splits `source` at `delimiter`, reporting the missing delimiter otherwise.
+pub fn split_once<'a>(source: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    source
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::end_of(source, format!("`{delimiter}`")))
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
@@ -0,0 +1,85 @@
+use std::{fmt::Display, io::BufRead, str::FromStr};
+
+mod error;
+
+pub use error::{column_of, parse_token, split_once, Error, ParseError};
+
+/// A puzzle solution, split into parsing and the two puzzle parts so both
+/// parts can share the parsed input.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, Error>;
+
+    fn parse_str(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input.as_bytes()).map_err(|err| match err {
+            Error::Parse(err) => err,
+            Error::Io(err) => unreachable!("reading from a string does not fail: {err}"),
+        })
+    }
+
+    /// Checks that `part` can be solved for `input`, for requirements only
+    /// one of the parts has, like a node that only part one starts from.
+    /// The parts may assume this passed.
+    fn check(_input: &Self::Input, _part: Part) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part {s}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Parses the input once and solves each of the requested parts, formatting
+/// the answers in the same order. Nothing is solved unless every part passes
+/// [`Solution::check`].
+pub fn solve<S: Solution>(reader: impl BufRead, parts: &[Part]) -> Result<Vec<String>, Error> {
+    Ok(solve_input::<S>(&S::parse(reader)?, parts)?)
+}
+
+/// Like [`solve`], for an input parsed some other way than [`Solution::parse`].
+pub fn solve_input<S: Solution>(
+    input: &S::Input,
+    parts: &[Part],
+) -> Result<Vec<String>, ParseError> {
+    for &part in parts {
+        S::check(input, part)?;
+    }
+
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            Part::One => S::part1(input).to_string(),
+            Part::Two => S::part2(input).to_string(),
+        })
+        .collect())
+}
@@ -0,0 +1,469 @@
+use std::{
+    fmt::Display,
+    io::BufRead,
+    iter::StepBy,
+    ops::{Index, IndexMut},
+    slice::{ChunksExact, ChunksExactMut},
+};
+
+use common::{Error, ParseError};
+
+mod geometry;
+
+pub use geometry::{Direction, Point};
+
+/// A `(row, column)` position inside a grid.
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line of `reader`. `parse_cell` reports errors at
+    /// column 1, they are moved to the cell's actual position.
+    pub fn parse(
+        reader: impl BufRead,
+        mut parse_cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, Error> {
+        let mut grid = Self::default();
+
+        for (line_idx, line) in reader.lines().enumerate() {
+            grid.parse_row(&line?, &mut parse_cell)
+                .map_err(|err| err.at_line(line_idx + 1))?;
+        }
+
+        Ok(grid)
+    }
+
+    pub fn parse_str(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Self::default();
+
+        for (line_idx, line) in input.lines().enumerate() {
+            grid.parse_row(line, &mut parse_cell)
+                .map_err(|err| err.at_line(line_idx + 1))?;
+        }
+
+        Ok(grid)
+    }
+
+    /// Appends `line` as a new row. The first row decides the width of the
+    /// grid, every following row has to match it.
+    pub fn parse_row(
+        &mut self,
+        line: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<(), ParseError> {
+        let len_before = self.cells.len();
+
+        for (idx, (byte_idx, c)) in line.char_indices().enumerate() {
+            if self.height > 0 && idx == self.width {
+                self.cells.truncate(len_before);
+                return Err(ParseError::new(
+                    idx + 1,
+                    "end of row",
+                    Some(&line[byte_idx..]),
+                ));
+            }
+
+            match parse_cell(c) {
+                Ok(cell) => self.cells.push(cell),
+                Err(err) => {
+                    self.cells.truncate(len_before);
+                    return Err(err.shifted(idx));
+                }
+            }
+        }
+
+        let row_width = self.cells.len() - len_before;
+        if self.height == 0 {
+            self.width = row_width;
+        } else if row_width < self.width {
+            self.cells.truncate(len_before);
+            return Err(ParseError::end_of(
+                line,
+                format!("row of {} cells", self.width),
+            ));
+        }
+
+        self.height += 1;
+        Ok(())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Moves `pos` by `(rows, columns)`, or `None` if that leaves the grid.
+    pub fn offset(&self, (row, col): Position, (rows, cols): (isize, isize)) -> Option<Position> {
+        let pos = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Moves `pos` one cell in `direction`, or `None` if that leaves the grid.
+    pub fn step(&self, pos: Position, direction: Direction) -> Option<Position> {
+        self.step_by(pos, direction, 1)
+    }
+
+    pub fn step_by(
+        &self,
+        pos: Position,
+        direction: Direction,
+        distance: usize,
+    ) -> Option<Position> {
+        let Point { x, y } = direction.unit() * isize::try_from(distance).ok()?;
+
+        self.offset(pos, (y, x))
+    }
+
+    /// Converts a point into the position of the cell it lies on, if any.
+    pub fn position_of(&self, Point { x, y }: Point) -> Option<Position> {
+        let pos = (usize::try_from(y).ok()?, usize::try_from(x).ok()?);
+
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to 4 horizontally and vertically adjacent positions.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&diff| self.offset(pos, diff))
+    }
+
+    /// The up to 8 adjacent positions, including diagonals, in row order.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&diff| self.offset(pos, diff))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Removes the top row, keeping the width for rows parsed later on.
+    pub fn pop_front_row(&mut self) -> Option<Vec<T>> {
+        if self.height == 0 {
+            return None;
+        }
+
+        self.height -= 1;
+        Some(self.cells.drain(..self.width).collect())
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> StepBy<std::slice::Iter<'_, T>> {
+        assert!(col < self.width, "column {col} is out of bounds");
+
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = StepBy<std::slice::Iter<'_, T>>> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, in row order, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| (idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.columns().flat_map(|col| col.rev()).cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|col| self.column(col))
+                .cloned()
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, col): Position) -> Self {
+        Point::new(col as isize, row as isize)
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self {
+            cells: vec![],
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} is out of bounds for a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!("position {pos:?} is out of bounds for a {width}x{height} grid")
+        })
+    }
+}
+
+/// Prints one line per row without a trailing newline, so cells that print
+/// as the character they were parsed from read back into the same grid.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row_idx, row) in self.rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse_str(input, Ok).unwrap()
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let transposed = grid("abc\ndef").transpose();
+
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed, grid("ad\nbe\ncf"));
+        assert_eq!(transposed.transpose(), grid("abc\ndef"));
+    }
+
+    #[test]
+    fn rotations_turn_a_quarter() {
+        let original = grid("abc\ndef");
+
+        assert_eq!(original.rotate_clockwise(), grid("da\neb\nfc"));
+        assert_eq!(original.rotate_counter_clockwise(), grid("cf\nbe\nad"));
+        assert_eq!(
+            original.rotate_clockwise().rotate_counter_clockwise(),
+            original
+        );
+
+        let mut turned = original.clone();
+        for _ in 0..4 {
+            turned = turned.rotate_clockwise();
+        }
+        assert_eq!(turned, original);
+    }
+
+    #[test]
+    fn display_parses_back() {
+        for input in ["abc\ndef", "a\nb\nc", "#..#", ""] {
+            let parsed = grid(input);
+
+            assert_eq!(parsed.to_string(), input);
+            assert_eq!(grid(&parsed.to_string()), parsed);
+        }
+    }
+
+    #[test]
+    fn rejects_rows_that_are_too_long() {
+        let err = Grid::parse_str("abc\nabcde\nabc", Ok).unwrap_err();
+
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), 4);
+        assert_eq!(err.expected(), "end of row");
+        assert_eq!(err.found(), Some("de"));
+    }
+
+    #[test]
+    fn rejects_rows_that_are_too_short() {
+        let err = Grid::parse_str("abc\nab", Ok).unwrap_err();
+
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), 3);
+        assert_eq!(err.expected(), "row of 3 cells");
+        assert_eq!(err.found(), None);
+    }
+
+    #[test]
+    fn rejected_rows_leave_the_grid_unchanged() {
+        let mut parsed = grid("abc");
+
+        assert!(parsed.parse_row("ab", Ok).is_err());
+        assert!(parsed.parse_row("abcd", Ok).is_err());
+        assert_eq!(parsed, grid("abc"));
+        parsed.parse_row("def", Ok).unwrap();
+        assert_eq!(parsed, grid("abc\ndef"));
+    }
+
+    fn cells(grid: &Grid<char>, positions: impl Iterator<Item = Position>) -> String {
+        positions.map(|pos| grid[pos]).collect()
+    }
+
+    #[test]
+    fn neighbours_stop_at_corners() {
+        let grid = grid("abc\ndef\nghi");
+
+        assert_eq!(cells(&grid, grid.neighbours4((0, 0))), "bd");
+        assert_eq!(cells(&grid, grid.neighbours8((0, 0))), "bde");
+        assert_eq!(cells(&grid, grid.neighbours4((0, 2))), "bf");
+        assert_eq!(cells(&grid, grid.neighbours8((0, 2))), "bef");
+        assert_eq!(cells(&grid, grid.neighbours4((2, 0))), "dh");
+        assert_eq!(cells(&grid, grid.neighbours8((2, 0))), "deh");
+        assert_eq!(cells(&grid, grid.neighbours4((2, 2))), "fh");
+        assert_eq!(cells(&grid, grid.neighbours8((2, 2))), "efh");
+    }
+
+    #[test]
+    fn neighbours_stop_at_edges() {
+        let grid = grid("abc\ndef\nghi");
+
+        assert_eq!(cells(&grid, grid.neighbours4((0, 1))), "ace");
+        assert_eq!(cells(&grid, grid.neighbours8((0, 1))), "acdef");
+        assert_eq!(cells(&grid, grid.neighbours4((1, 0))), "aeg");
+        assert_eq!(cells(&grid, grid.neighbours8((1, 0))), "abegh");
+        assert_eq!(cells(&grid, grid.neighbours4((1, 2))), "cei");
+        assert_eq!(cells(&grid, grid.neighbours8((2, 1))), "defgi");
+        assert_eq!(cells(&grid, grid.neighbours4((1, 1))), "bdfh");
+        assert_eq!(cells(&grid, grid.neighbours8((1, 1))), "abcdfghi");
+    }
+
+    #[test]
+    fn neighbours_of_a_single_cell_or_row() {
+        let single = grid("a");
+        assert_eq!(single.neighbours8((0, 0)).count(), 0);
+
+        let row = grid("abcd");
+        assert_eq!(cells(&row, row.neighbours4((0, 0))), "b");
+        assert_eq!(cells(&row, row.neighbours8((0, 2))), "bd");
+    }
+}